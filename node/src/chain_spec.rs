@@ -14,8 +14,9 @@ use sp_runtime::{
 };
 // Frontier
 use impetus_runtime::{
-	constants::currency::*, AccountId, Balance, MaxNominations, RuntimeGenesisConfig, SS58Prefix,
-	SessionKeys, Signature, StakerStatus, BABE_GENESIS_EPOCH_CONFIG, WASM_BINARY,
+	constants::currency::*, AccountId, Balance, FastUnstakeErasToCheckPerBlock, MaxNominations,
+	MaxPoolMembers, MaxPools, PoolsMinCreateBond, PoolsMinJoinBond, RuntimeGenesisConfig,
+	SS58Prefix, SessionKeys, Signature, StakerStatus, BABE_GENESIS_EPOCH_CONFIG, WASM_BINARY,
 };
 
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -310,6 +311,15 @@ fn testnet_genesis(
 			"minValidatorBond": 100 * IPT,
 			"minNominatorBond": 10 * IPT,
 		},
+		"nominationPools": {
+			"minCreateBond": PoolsMinCreateBond::get(),
+			"minJoinBond": PoolsMinJoinBond::get(),
+			"maxPools": MaxPools::get(),
+			"maxMembers": MaxPoolMembers::get(),
+		},
+		"fastUnstake": {
+			"erasToCheckPerBlock": FastUnstakeErasToCheckPerBlock::get(),
+		},
 		"elections": {
 			"members": endowed_accounts
 				.iter()
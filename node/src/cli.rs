@@ -65,4 +65,7 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	FrontierDb(fc_cli::FrontierDbCmd),
+
+	/// Export the Solidity interfaces and JSON ABIs of the precompiles.
+	ExportPrecompileInterfaces(crate::interfaces::ExportPrecompileInterfacesCmd),
 }
@@ -241,6 +241,7 @@ pub fn run() -> sc_cli::Result<()> {
 				cmd.run(client, frontier_backend)
 			})
 		}
+		Some(Subcommand::ExportPrecompileInterfaces(cmd)) => cmd.run(),
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
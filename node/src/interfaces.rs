@@ -0,0 +1,46 @@
+use std::{fs, path::PathBuf};
+
+use impetus_runtime::{FrontierPrecompiles, Runtime};
+
+/// Writes the Solidity interface and the JSON ABI of every precompile of the runtime, for
+/// generating contract bindings.
+#[derive(Debug, clap::Parser)]
+pub struct ExportPrecompileInterfacesCmd {
+	/// Directory to write the `.sol` and `.json` files to, created if missing.
+	#[arg(long, short, default_value = "precompile-interfaces")]
+	pub output: PathBuf,
+}
+
+impl ExportPrecompileInterfacesCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let interfaces = precompile_interfaces::interfaces().map_err(|e| e.to_string())?;
+		let addresses = FrontierPrecompiles::<Runtime>::used_addresses();
+
+		fs::create_dir_all(&self.output)?;
+		for address in addresses {
+			let interface = interfaces
+				.iter()
+				.find(|interface| interface.address == address)
+				.ok_or_else(|| {
+					format!("No Solidity interface for the precompile at {address:?}")
+				})?;
+			let abi = serde_json::to_string_pretty(&interface.abi()).map_err(|e| e.to_string())?;
+
+			fs::write(
+				self.output.join(format!("{}.sol", interface.name)),
+				interface.source,
+			)?;
+			fs::write(
+				self.output.join(format!("{}.json", interface.name)),
+				abi + "\n",
+			)?;
+		}
+
+		println!(
+			"{} precompile interfaces written to {:?}.",
+			addresses.len(),
+			self.output
+		);
+		Ok(())
+	}
+}
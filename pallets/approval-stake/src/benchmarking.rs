@@ -0,0 +1,65 @@
+//! Benchmarks for the approval stake pallet.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use pallet_staking::{testing_utils, RewardDestination};
+use sp_runtime::traits::StaticLookup;
+
+/// A nominator of `MaxNominationsOf<T>` validators, which are in the target list.
+fn nominator_of_all_targets<T: Config>() -> Result<T::AccountId, BenchmarkError> {
+	let count = MaxNominationsOf::<T>::get();
+	testing_utils::create_validators::<T>(count, 100)?;
+	let targets: Vec<_> = pallet_staking::Validators::<T>::iter_keys()
+		.take(count as usize)
+		.map(T::Lookup::unlookup)
+		.collect();
+
+	// After the stashes of the validators.
+	let (stash, controller) =
+		testing_utils::create_stash_controller::<T>(count, 100, RewardDestination::Staked)?;
+	pallet_staking::Pallet::<T>::nominate(RawOrigin::Signed(controller).into(), targets)?;
+	Pallet::<T>::sync(Weight::MAX);
+	Ok(stash)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn sync_voter() -> Result<(), BenchmarkError> {
+		let nominator = nominator_of_all_targets::<T>()?;
+		// As if its nominations changed: every target is counted again and re-scored.
+		let (stake, targets) = Counted::<T>::take(&nominator).ok_or("nominator not counted")?;
+		for target in &targets {
+			NominatorStake::<T>::remove(target);
+		}
+
+		#[block]
+		{
+			Pallet::<T>::sync_voter(&nominator);
+		}
+
+		assert!(targets
+			.iter()
+			.all(|target| NominatorStake::<T>::get(target) == stake));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn count_unchanged() -> Result<(), BenchmarkError> {
+		let nominator = nominator_of_all_targets::<T>()?;
+		let changed;
+
+		#[block]
+		{
+			changed = Pallet::<T>::count(&nominator);
+		}
+
+		assert!(!changed);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,336 @@
+//! Approval stake of the validators, by which the staking target list is sorted.
+//!
+//! The approval stake of a validator is its own active bond plus the active bond of every
+//! nominator nominating it, elected or not. Staking reports every change of its voters,
+//! validators included, to its voter list but not to its target list, and does not report
+//! changes of nominations at all. This pallet keeps the target list in sync:
+//!
+//! - [`VotersAndTargets`] is the voter list of staking. It forwards every change to `T::Voters`,
+//!   removes a validator that stops validating from the target list at once, and queues the
+//!   voter in [`PendingVoters`].
+//! - In `on_idle`, with the weight left in blocks, the queued voters are synced first: a
+//!   validator is inserted in or re-scored in the target list, and the bond and nominations of a
+//!   nominator are counted again. Then at most `MaxNominatorsPerBlock` nominators are counted
+//!   again, in turn, so that nominations changed with `nominate` count once the pass over all
+//!   nominators reaches them.
+//!
+//! The bond and targets counted for each nominator are kept in [`Counted`], so counting it again
+//! only applies the difference to the [`NominatorStake`] of its old and new targets.
+//!
+//! The queued voters are synced before the genesis session by [`SyncGenesisTargets`], as the
+//! genesis election reads the target list.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_election_provider_support::{ScoreProvider, SortedListProvider, VoteWeight};
+use frame_support::pallet_prelude::*;
+use pallet_staking::{BalanceOf, MaxNominationsOf};
+use sp_runtime::{traits::Zero, Saturating};
+use sp_std::{boxed::Box, collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
+
+/// The in-code storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_staking::Config {
+		/// List of the voters that [`VotersAndTargets`] forwards the changes of staking to.
+		type Voters: SortedListProvider<Self::AccountId, Score = VoteWeight>;
+
+		/// List of the validators that staking uses as its target list, scored by this pallet.
+		type Targets: SortedListProvider<Self::AccountId, Score = BalanceOf<Self>>;
+
+		/// Maximum number of nominators counted again in a block by the pass over all of them.
+		#[pallet::constant]
+		type MaxNominatorsPerBlock: Get<u32>;
+
+		/// Weight information for the syncs of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Active bond of the nominators nominating each account, as counted so far.
+	#[pallet::storage]
+	pub type NominatorStake<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Active bond and targets of each nominator, as counted in [`NominatorStake`].
+	#[pallet::storage]
+	pub type Counted<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(BalanceOf<T>, BoundedVec<T::AccountId, MaxNominationsOf<T>>),
+	>;
+
+	/// Voters that changed since they were last synced.
+	#[pallet::storage]
+	pub type PendingVoters<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ()>;
+
+	/// Last nominator counted by the pass over all nominators, after which the pass resumes.
+	#[pallet::storage]
+	pub type LastCounted<T: Config> = StorageValue<_, T::AccountId>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sync(remaining_weight)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Syncs the queued voters, then counts the next nominators of the pass, within `limit`.
+		pub(crate) fn sync(limit: Weight) -> Weight {
+			// LastCounted, read and written back
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+			let sync_voter = <T as Config>::WeightInfo::sync_voter();
+			let fits = |used: Weight| !used.saturating_add(sync_voter).any_gt(limit);
+			if !fits(used) {
+				return Weight::zero();
+			}
+
+			for who in PendingVoters::<T>::iter_keys() {
+				if !fits(used) {
+					return used;
+				}
+				PendingVoters::<T>::remove(&who);
+				Self::sync_voter(&who);
+				used.saturating_accrue(sync_voter);
+			}
+
+			let mut last = LastCounted::<T>::get();
+			let nominators = match &last {
+				Some(nominator) => pallet_staking::Nominators::<T>::iter_keys_from(
+					pallet_staking::Nominators::<T>::hashed_key_for(nominator),
+				),
+				None => pallet_staking::Nominators::<T>::iter_keys(),
+			};
+			let mut counted = 0;
+			let mut passed = true;
+			for nominator in nominators {
+				if counted >= T::MaxNominatorsPerBlock::get() || !fits(used) {
+					passed = false;
+					break;
+				}
+				counted.saturating_inc();
+
+				used.saturating_accrue(if Self::count(&nominator) {
+					sync_voter
+				} else {
+					<T as Config>::WeightInfo::count_unchanged()
+				});
+				last = Some(nominator);
+			}
+
+			LastCounted::<T>::set(if passed { None } else { last });
+			used
+		}
+
+		/// Counts the nominations of `who` again, and inserts, re-scores or removes it in the
+		/// target list as it validates or not.
+		pub(crate) fn sync_voter(who: &T::AccountId) {
+			Self::count(who);
+
+			if pallet_staking::Validators::<T>::contains_key(who) {
+				let score = Self::score(who);
+				let _ = if T::Targets::contains(who) {
+					T::Targets::on_update(who, score)
+				} else {
+					T::Targets::on_insert(who.clone(), score)
+				};
+			} else if T::Targets::contains(who) {
+				let _ = T::Targets::on_remove(who);
+			}
+		}
+
+		/// Counts the active bond and the nominations of `nominator` again, returning whether
+		/// they changed since they were last counted.
+		pub(crate) fn count(nominator: &T::AccountId) -> bool {
+			let targets = pallet_staking::Nominators::<T>::get(nominator)
+				.map(|nominations| nominations.targets)
+				.unwrap_or_default();
+			let stake = if targets.is_empty() {
+				Zero::zero()
+			} else {
+				active_stake::<T>(nominator)
+			};
+			let (old_stake, old_targets) = Counted::<T>::get(nominator).unwrap_or_default();
+			if stake == old_stake && targets == old_targets {
+				return false;
+			}
+
+			// The old and new stake counted for each target, once however often it is nominated.
+			let mut changes = BTreeMap::<T::AccountId, (BalanceOf<T>, BalanceOf<T>)>::new();
+			for target in old_targets {
+				changes.entry(target).or_default().0 = old_stake;
+			}
+			for target in &targets {
+				changes.entry(target.clone()).or_default().1 = stake;
+			}
+			for (target, (old, new)) in changes {
+				if old != new {
+					Self::adjust(&target, old, new);
+				}
+			}
+
+			if targets.is_empty() {
+				Counted::<T>::remove(nominator);
+			} else {
+				Counted::<T>::insert(nominator, (stake, targets));
+			}
+			true
+		}
+
+		/// Replaces `old` with `new` in the nominator stake of `target`, and re-scores it.
+		fn adjust(target: &T::AccountId, old: BalanceOf<T>, new: BalanceOf<T>) {
+			NominatorStake::<T>::mutate_exists(target, |stake| {
+				let updated = stake
+					.unwrap_or_default()
+					.saturating_sub(old)
+					.saturating_add(new);
+				*stake = (!updated.is_zero()).then_some(updated);
+			});
+			if T::Targets::contains(target) {
+				let _ = T::Targets::on_update(target, Self::score(target));
+			}
+		}
+	}
+}
+
+/// Active bond of a stash, or zero if it is not bonded.
+fn active_stake<T: pallet_staking::Config>(stash: &T::AccountId) -> BalanceOf<T> {
+	pallet_staking::Bonded::<T>::get(stash)
+		.and_then(pallet_staking::Ledger::<T>::get)
+		.map(|ledger| ledger.active)
+		.unwrap_or_default()
+}
+
+/// Scores validators by their approval stake: their own active bond, read as it is, plus the
+/// active bond of their nominators, as counted so far.
+impl<T: Config> ScoreProvider<T::AccountId> for Pallet<T> {
+	type Score = BalanceOf<T>;
+
+	fn score(who: &T::AccountId) -> BalanceOf<T> {
+		active_stake::<T>(who).saturating_add(NominatorStake::<T>::get(who))
+	}
+}
+
+/// The voter list of staking, `T::Voters`, which also keeps the target list in sync.
+///
+/// A voter is only known to be a validator or a nominator once the call that changed it
+/// completes, so it is queued and synced in a later block, except that a validator that stops
+/// validating is removed from the target list at once, before an election can read it.
+pub struct VotersAndTargets<T>(PhantomData<T>);
+
+impl<T: Config> SortedListProvider<T::AccountId> for VotersAndTargets<T> {
+	type Error = <T::Voters as SortedListProvider<T::AccountId>>::Error;
+	type Score = VoteWeight;
+
+	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
+		T::Voters::iter()
+	}
+
+	fn iter_from(
+		start: &T::AccountId,
+	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, Self::Error> {
+		T::Voters::iter_from(start)
+	}
+
+	fn count() -> u32 {
+		T::Voters::count()
+	}
+
+	fn contains(who: &T::AccountId) -> bool {
+		T::Voters::contains(who)
+	}
+
+	fn on_insert(who: T::AccountId, score: VoteWeight) -> Result<(), Self::Error> {
+		T::Voters::on_insert(who.clone(), score)?;
+		PendingVoters::<T>::insert(who, ());
+		Ok(())
+	}
+
+	fn on_update(who: &T::AccountId, score: VoteWeight) -> Result<(), Self::Error> {
+		T::Voters::on_update(who, score)?;
+		PendingVoters::<T>::insert(who, ());
+		Ok(())
+	}
+
+	fn get_score(who: &T::AccountId) -> Result<VoteWeight, Self::Error> {
+		T::Voters::get_score(who)
+	}
+
+	fn on_remove(who: &T::AccountId) -> Result<(), Self::Error> {
+		T::Voters::on_remove(who)?;
+		// Staking removes a validator before reporting it.
+		if T::Targets::contains(who) && !pallet_staking::Validators::<T>::contains_key(who) {
+			let _ = T::Targets::on_remove(who);
+		}
+		PendingVoters::<T>::insert(who, ());
+		Ok(())
+	}
+
+	fn unsafe_regenerate(
+		all: impl IntoIterator<Item = T::AccountId>,
+		score_of: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
+	) -> u32 {
+		T::Voters::unsafe_regenerate(all, score_of)
+	}
+
+	fn unsafe_clear() {
+		T::Voters::unsafe_clear()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn score_update_worst_case(who: &T::AccountId, is_increase: bool) -> VoteWeight {
+		T::Voters::score_update_worst_case(who, is_increase)
+	}
+}
+
+/// Syncs the queued voters before the genesis session of the `Inner` session manager, so that
+/// the genesis validators are in the target list when the genesis election reads it.
+pub struct SyncGenesisTargets<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, FullIdentification>
+	pallet_session::historical::SessionManager<T::AccountId, FullIdentification>
+	for SyncGenesisTargets<T, Inner>
+where
+	T: Config,
+	Inner: pallet_session::historical::SessionManager<T::AccountId, FullIdentification>,
+{
+	fn new_session(new_index: u32) -> Option<Vec<(T::AccountId, FullIdentification)>> {
+		Inner::new_session(new_index)
+	}
+
+	fn new_session_genesis(new_index: u32) -> Option<Vec<(T::AccountId, FullIdentification)>> {
+		Pallet::<T>::sync(Weight::MAX);
+		Inner::new_session_genesis(new_index)
+	}
+
+	fn start_session(start_index: u32) {
+		Inner::start_session(start_index)
+	}
+
+	fn end_session(end_index: u32) {
+		Inner::end_session(end_index)
+	}
+}
@@ -0,0 +1,161 @@
+//! Test environment for the approval stake pallet.
+
+use crate as pallet_approval_stake;
+use frame_election_provider_support::{
+	bounds::{ElectionBounds, ElectionBoundsBuilder},
+	onchain, SequentialPhragmen, VoteWeight,
+};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, Hooks},
+	weights::Weight,
+};
+use pallet_staking::StakerStatus;
+use sp_runtime::{BuildStorage, Perbill};
+use sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		Staking: pallet_staking,
+		VoterList: pallet_bags_list::<Instance1>,
+		TargetList: pallet_bags_list::<Instance2>,
+		ApprovalStake: pallet_approval_stake,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+	pub const VoterBagThresholds: &'static [VoteWeight] = &[];
+	pub const TargetBagThresholds: &'static [u64] = &[];
+}
+
+pub struct OnChainSeqPhragmen;
+
+impl onchain::Config for OnChainSeqPhragmen {
+	type System = Test;
+	type Solver = SequentialPhragmen<u64, Perbill>;
+	type DataProvider = Staking;
+	type WeightInfo = ();
+	type MaxWinners = ConstU32<10>;
+	type Bounds = ElectionsBounds;
+}
+
+impl pallet_staking::Config for Test {
+	type Currency = Balances;
+	type CurrencyBalance = u64;
+	type UnixTime = Timestamp;
+	type CurrencyToVote = SaturatingCurrencyToVote;
+	type RewardRemainder = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = ConstU32<1>;
+	type BondingDuration = ConstU32<3>;
+	type SlashDeferDuration = ConstU32<0>;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type SessionInterface = ();
+	type EraPayout = ();
+	type NextNewSession = ();
+	type MaxExposurePageSize = ConstU32<64>;
+	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+	type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+	type GenesisElectionProvider = Self::ElectionProvider;
+	type VoterList = pallet_approval_stake::VotersAndTargets<Self>;
+	type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+	type TargetList = TargetList;
+	type MaxUnlockingChunks = ConstU32<32>;
+	type HistoryDepth = ConstU32<84>;
+	type EventListeners = ();
+	type MaxControllersInDeprecationBatch = ConstU32<100>;
+	type WeightInfo = ();
+	type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+}
+
+impl pallet_bags_list::Config<pallet_bags_list::Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ScoreProvider = Staking;
+	type BagThresholds = VoterBagThresholds;
+	type Score = VoteWeight;
+	type WeightInfo = ();
+}
+
+impl pallet_bags_list::Config<pallet_bags_list::Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ScoreProvider = ApprovalStake;
+	type BagThresholds = TargetBagThresholds;
+	type Score = u64;
+	type WeightInfo = ();
+}
+
+impl pallet_approval_stake::Config for Test {
+	type Voters = VoterList;
+	type Targets = TargetList;
+	type MaxNominatorsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+/// Runs `on_idle` with the whole block weight left.
+pub fn sync() -> Weight {
+	ApprovalStake::on_idle(System::block_number(), Weight::MAX)
+}
+
+/// Validators 11 and 21, nominated by 101 and 102, and by 103 for 21 only. Nominators are
+/// counted two per block, and the voters of genesis are synced.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: [11, 21, 31, 101, 102, 103, 104]
+			.into_iter()
+			.map(|account| (account, 10_000))
+			.collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_staking::GenesisConfig::<Test> {
+		validator_count: 2,
+		minimum_validator_count: 1,
+		stakers: vec![
+			(11, 11, 1_000, StakerStatus::Validator),
+			(21, 21, 2_000, StakerStatus::Validator),
+			(101, 101, 100, StakerStatus::Nominator(vec![11, 21])),
+			(102, 102, 200, StakerStatus::Nominator(vec![11, 21])),
+			(103, 103, 400, StakerStatus::Nominator(vec![21])),
+		],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		sync();
+	});
+	ext
+}
@@ -0,0 +1,125 @@
+use crate::{mock::*, Counted, LastCounted, PendingVoters, WeightInfo};
+use frame_election_provider_support::SortedListProvider;
+use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+use pallet_staking::{RewardDestination, ValidatorPrefs};
+
+fn score(validator: u64) -> Option<u64> {
+	TargetList::get_score(&validator).ok()
+}
+
+#[test]
+fn validators_are_scored_by_approval_stake() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TargetList::count(), 2);
+		assert_eq!(score(11), Some(1_000 + 100 + 200));
+		assert_eq!(score(21), Some(2_000 + 100 + 200 + 400));
+
+		let (stake, targets) = Counted::<Test>::get(101).unwrap();
+		assert_eq!((stake, targets.into_inner()), (100, vec![11, 21]));
+		assert!(Counted::<Test>::get(11).is_none());
+		assert_eq!(PendingVoters::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn voters_are_synced_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		// Stake changes of nominators and validators.
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(102), 50));
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(21), 10));
+		assert_eq!(score(11), Some(1_300));
+		assert_eq!(score(21), Some(2_700));
+		sync();
+		assert_eq!(score(11), Some(1_350));
+		assert_eq!(score(21), Some(2_760));
+
+		// New validators and nominators.
+		assert_ok!(Staking::bond(
+			RuntimeOrigin::signed(31),
+			500,
+			RewardDestination::Staked
+		));
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(31),
+			ValidatorPrefs::default()
+		));
+		assert_ok!(Staking::bond(
+			RuntimeOrigin::signed(104),
+			300,
+			RewardDestination::Staked
+		));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(104), vec![31]));
+		assert!(!TargetList::contains(&31));
+		sync();
+		assert_eq!(score(31), Some(800));
+
+		// A validator that stops validating leaves the target list at once.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(11)));
+		assert!(!TargetList::contains(&11));
+		sync();
+		assert!(!TargetList::contains(&11));
+
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(104)));
+		assert_eq!(score(31), Some(800));
+		sync();
+		assert_eq!(score(31), Some(500));
+		assert!(Counted::<Test>::get(104).is_none());
+	});
+}
+
+#[test]
+fn nominations_count_once_the_pass_reaches_them() {
+	new_test_ext().execute_with(|| {
+		// Staking does not report nominations changed by a nominator.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+		assert!(!PendingVoters::<Test>::contains_key(101));
+		assert_eq!(score(21), Some(2_700));
+
+		// Two of the three nominators are counted per block, where genesis left the pass.
+		let mut blocks = 0;
+		while score(21) == Some(2_700) {
+			assert!(blocks < 2, "the pass did not reach the nominator");
+			sync();
+			blocks += 1;
+		}
+		assert_eq!(score(11), Some(1_300));
+		assert_eq!(score(21), Some(2_600));
+	});
+}
+
+#[test]
+fn sync_stays_within_the_block_limits() {
+	new_test_ext().execute_with(|| {
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let last_counted = db.reads_writes(1, 1);
+		let sync_voter = <() as WeightInfo>::sync_voter();
+		let count_unchanged = <() as WeightInfo>::count_unchanged();
+
+		// Unchanged nominators are counted two per block.
+		let used = sync();
+		assert!(used.all_lte(last_counted + count_unchanged * 2));
+		assert!(used.any_gt(last_counted));
+
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(101), 10));
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(102), 10));
+		let last = LastCounted::<Test>::get();
+
+		assert_eq!(
+			ApprovalStake::on_idle(1, last_counted + sync_voter - Weight::from_parts(1, 0)),
+			Weight::zero()
+		);
+		assert_eq!(PendingVoters::<Test>::iter().count(), 2);
+
+		// One voter fits, and the pass waits for the next block.
+		assert_eq!(
+			ApprovalStake::on_idle(1, last_counted + sync_voter),
+			last_counted + sync_voter
+		);
+		assert_eq!(PendingVoters::<Test>::iter().count(), 1);
+		assert_eq!(LastCounted::<Test>::get(), last);
+
+		sync();
+		assert_eq!(PendingVoters::<Test>::iter().count(), 0);
+		assert_eq!(score(11), Some(1_320));
+	});
+}
@@ -0,0 +1,77 @@
+//! Weights for `pallet_approval_stake`.
+//!
+//! The weights below are placeholders, the storage accesses of the benchmarked cases on top of a
+//! fixed base, until the benchmarks of `benchmarking.rs` are run on the reference hardware, with
+//! a node built with the `runtime-benchmarks` feature:
+//!
+//! ```text
+//! impetus-node benchmark pallet --chain dev --pallet pallet_approval_stake --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/approval-stake/src/weights.rs
+//! ```
+//!
+//! `sync_voter` counts the 16 targets of a nominator again, re-scoring each of them in the
+//! target list.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_approval_stake`.
+pub trait WeightInfo {
+	fn sync_voter() -> Weight;
+	fn count_unchanged() -> Weight;
+}
+
+/// Weights for `pallet_approval_stake` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:17 w:0)
+	/// Storage: `Staking::Ledger` (r:17 w:0)
+	/// Storage: `ApprovalStake::Counted` (r:1 w:1)
+	/// Storage: `ApprovalStake::NominatorStake` (r:16 w:16)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `TargetList::ListNodes` (r:48 w:48)
+	/// Storage: `TargetList::ListBags` (r:32 w:32)
+	fn sync_voter() -> Weight {
+		Weight::from_parts(400_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(133_u64))
+			.saturating_add(T::DbWeight::get().writes(97_u64))
+	}
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `ApprovalStake::Counted` (r:1 w:0)
+	fn count_unchanged() -> Weight {
+		Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads(4_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:17 w:0)
+	/// Storage: `Staking::Ledger` (r:17 w:0)
+	/// Storage: `ApprovalStake::Counted` (r:1 w:1)
+	/// Storage: `ApprovalStake::NominatorStake` (r:16 w:16)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `TargetList::ListNodes` (r:48 w:48)
+	/// Storage: `TargetList::ListBags` (r:32 w:32)
+	fn sync_voter() -> Weight {
+		Weight::from_parts(400_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(133_u64))
+			.saturating_add(RocksDbWeight::get().writes(97_u64))
+	}
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `ApprovalStake::Counted` (r:1 w:0)
+	fn count_unchanged() -> Weight {
+		Weight::from_parts(15_000_000, 0).saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+}
@@ -0,0 +1,66 @@
+//! Benchmarks for the auto-payout pallet.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use pallet_staking::{testing_utils, RewardDestination, ValidatorPrefs};
+
+/// A validator stash, with auto-payout enabled if `enabled`.
+fn validator<T: Config>(enabled: bool) -> Result<T::AccountId, BenchmarkError> {
+	let (stash, controller) =
+		testing_utils::create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
+	pallet_staking::Pallet::<T>::validate(
+		RawOrigin::Signed(controller).into(),
+		ValidatorPrefs::default(),
+	)?;
+	if enabled {
+		Pallet::<T>::enable_auto_payout(RawOrigin::Signed(stash.clone()).into())?;
+	}
+	Ok(stash)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn enable_auto_payout() -> Result<(), BenchmarkError> {
+		let validator = validator::<T>(false)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(validator.clone()));
+
+		assert!(Payees::<T>::contains_key(&validator));
+		assert!(Backfills::<T>::contains_key(&validator));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disable_auto_payout() -> Result<(), BenchmarkError> {
+		let validator = validator::<T>(true)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(validator.clone()));
+
+		assert!(!Payees::<T>::contains_key(&validator));
+		assert!(!Backfills::<T>::contains_key(&validator));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_payee() -> Result<(), BenchmarkError> {
+		// A payee that is no longer bonded.
+		let validator: T::AccountId = account("validator", 0, 0);
+		Payees::<T>::insert(&validator, ());
+		Backfills::<T>::insert(&validator, (0, 1));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), validator.clone());
+
+		assert!(!Payees::<T>::contains_key(&validator));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,360 @@
+//! Automatic payout of the staking rewards of opted-in validators.
+//!
+//! Staking rewards are claimed per era and per page of nominators with `payout_stakers`, and
+//! those of the eras older than `HistoryDepth` are lost. A validator that enables auto-payout has
+//! its pages paid out in `on_idle`, with the weight left in blocks and at most `MaxPagesPerBlock`
+//! pages per block, oldest era first since its rewards are the closest to being lost.
+//!
+//! The payouts go through the eras that can still be paid out and, within each era, through the
+//! payees, keeping their position between blocks. A new payee is backfilled on its own: the eras
+//! the payouts already went through are paid out for it first, without restarting them for the
+//! other payees. Pages claimed with `payout_stakers` in the meantime are skipped, and each page is
+//! paid out in its own storage layer, so that a failed payout leaves no changes behind.
+//!
+//! For monitoring, [`Pallet::unclaimed_eras`] lists the eras in which a validator has pages left
+//! to pay out, and [`Pallet::backlog`] counts the ended eras the payouts have not gone through,
+//! backfills included.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+use pallet_staking::{ActiveEra, ClaimedRewards, CurrentEra, WeightInfo as _};
+use sp_runtime::Saturating;
+use sp_staking::EraIndex;
+use sp_std::vec::Vec;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_staking::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Maximum number of validators with auto-payout enabled.
+		#[pallet::constant]
+		type MaxPayees: Get<u32>;
+
+		/// Maximum number of pages paid out in a block.
+		#[pallet::constant]
+		type MaxPagesPerBlock: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Validators whose rewards are paid out automatically.
+	#[pallet::storage]
+	pub type Payees<T: Config> = CountedStorageMap<_, Twox64Concat, T::AccountId, ()>;
+
+	/// Era being paid out. The eras before it are paid out, or can no longer be.
+	#[pallet::storage]
+	pub type ScanEra<T> = StorageValue<_, EraIndex, ValueQuery>;
+
+	/// Last payee whose pages of `ScanEra` are all paid out, after which the payouts resume.
+	#[pallet::storage]
+	pub type LastPaid<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Payees to backfill, with the next era to pay out for them and the era from which the
+	/// payouts of all payees include them.
+	#[pallet::storage]
+	pub type Backfills<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (EraIndex, EraIndex)>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The rewards of `validator` are now paid out automatically.
+		AutoPayoutEnabled { validator: T::AccountId },
+		/// The rewards of `validator` are no longer paid out automatically.
+		AutoPayoutDisabled { validator: T::AccountId },
+		/// A page of `validator` in `era` could not be paid out. Its other pages of the era are
+		/// left to `payout_stakers`.
+		PayoutFailed {
+			validator: T::AccountId,
+			era: EraIndex,
+			error: DispatchError,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a validator.
+		NotValidator,
+		/// Auto-payout is already enabled for the validator.
+		AlreadyEnabled,
+		/// Auto-payout is not enabled for the validator.
+		NotEnabled,
+		/// Too many validators have auto-payout enabled.
+		TooManyPayees,
+		/// The validator is still bonded.
+		StillBonded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::pay_out(remaining_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enables auto-payout for the validator stash of the origin.
+		///
+		/// The eras the payouts already went through are backfilled for the validator alone, so
+		/// that the eras it earned before are paid out as well.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::enable_auto_payout())]
+		pub fn enable_auto_payout(origin: OriginFor<T>) -> DispatchResult {
+			let validator = ensure_signed(origin)?;
+			ensure!(
+				pallet_staking::Validators::<T>::contains_key(&validator),
+				Error::<T>::NotValidator
+			);
+			ensure!(
+				!Payees::<T>::contains_key(&validator),
+				Error::<T>::AlreadyEnabled
+			);
+			ensure!(
+				Payees::<T>::count() < T::MaxPayees::get(),
+				Error::<T>::TooManyPayees
+			);
+
+			Payees::<T>::insert(&validator, ());
+			// The payouts may have gone through part of `ScanEra` already.
+			Backfills::<T>::insert(&validator, (0, ScanEra::<T>::get().saturating_add(1)));
+
+			Self::deposit_event(Event::AutoPayoutEnabled { validator });
+			Ok(())
+		}
+
+		/// Disables auto-payout for the validator stash of the origin.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::disable_auto_payout())]
+		pub fn disable_auto_payout(origin: OriginFor<T>) -> DispatchResult {
+			let validator = ensure_signed(origin)?;
+			Self::remove(validator)
+		}
+
+		/// Disables auto-payout for `validator`, which is no longer bonded.
+		///
+		/// Any signed origin can make room for other validators this way.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_payee())]
+		pub fn remove_payee(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				!pallet_staking::Bonded::<T>::contains_key(&validator),
+				Error::<T>::StillBonded
+			);
+			Self::remove(validator)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Eras that can still be paid out in which `validator` has pages left to pay out.
+		pub fn unclaimed_eras(validator: &T::AccountId) -> Vec<EraIndex> {
+			let (Some(active_era), Some(current_era)) =
+				(ActiveEra::<T>::get(), CurrentEra::<T>::get())
+			else {
+				return Vec::new();
+			};
+
+			(current_era.saturating_sub(T::HistoryDepth::get())..active_era.index)
+				.filter(|era| Self::unclaimed_pages(*era, validator) > 0)
+				.collect()
+		}
+
+		/// Number of ended eras that the payouts have not gone through yet, for all payees or
+		/// for the ones to backfill.
+		pub fn backlog() -> u32 {
+			let Some(active_era) = ActiveEra::<T>::get() else {
+				return 0;
+			};
+			let oldest = CurrentEra::<T>::get()
+				.unwrap_or_default()
+				.saturating_sub(T::HistoryDepth::get());
+			let scan_era = Backfills::<T>::iter_values()
+				.map(|(next, _)| next)
+				.fold(ScanEra::<T>::get(), EraIndex::min);
+
+			active_era.index.saturating_sub(scan_era.max(oldest))
+		}
+
+		/// Number of pages of `validator` in `era` that are not paid out.
+		pub fn unclaimed_pages(era: EraIndex, validator: &T::AccountId) -> u32 {
+			let page_count =
+				pallet_staking::Pallet::<T>::api_eras_stakers_page_count(era, validator.clone());
+			let claimed = ClaimedRewards::<T>::decode_len(era, validator).unwrap_or_default();
+
+			page_count.saturating_sub(claimed as u32)
+		}
+
+		fn remove(validator: T::AccountId) -> DispatchResult {
+			ensure!(
+				Payees::<T>::contains_key(&validator),
+				Error::<T>::NotEnabled
+			);
+			Payees::<T>::remove(&validator);
+			Backfills::<T>::remove(&validator);
+
+			Self::deposit_event(Event::AutoPayoutDisabled { validator });
+			Ok(())
+		}
+
+		/// Pays out the pages of the payees that are not paid out yet, within `limit`.
+		///
+		/// The backfills go first, as the eras they pay out are older than `ScanEra`.
+		fn pay_out(limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			// ActiveEra, CurrentEra, ScanEra and LastPaid, then writing back the last two
+			let mut budget = Budget {
+				limit,
+				used: db.reads_writes(4, 2),
+				pages: 0,
+			};
+			if budget.used.any_gt(limit) {
+				return Weight::zero();
+			}
+			let (Some(active_era), Some(current_era)) =
+				(ActiveEra::<T>::get(), CurrentEra::<T>::get())
+			else {
+				return db.reads(2);
+			};
+			let oldest = current_era.saturating_sub(T::HistoryDepth::get());
+
+			if !Self::backfill(oldest, active_era.index, &mut budget) {
+				return budget.used;
+			}
+
+			let mut era = ScanEra::<T>::get();
+			let mut last = LastPaid::<T>::get();
+			if era < oldest {
+				era = oldest;
+				last = None;
+			}
+
+			'eras: while era < active_era.index {
+				let payees = match &last {
+					Some(payee) => Payees::<T>::iter_keys_from(Payees::<T>::hashed_key_for(payee)),
+					None => Payees::<T>::iter_keys(),
+				};
+				for validator in payees {
+					if !Self::pay_validator(era, &validator, &mut budget) {
+						break 'eras;
+					}
+					last = Some(validator);
+				}
+				era.saturating_inc();
+				last = None;
+			}
+
+			ScanEra::<T>::put(era);
+			LastPaid::<T>::set(last);
+			budget.used
+		}
+
+		/// Pays out the eras of the payees in [`Backfills`] up to `active_era`, returning whether
+		/// all of them fit in `budget`.
+		fn backfill(oldest: EraIndex, active_era: EraIndex, budget: &mut Budget) -> bool {
+			// The backfill, then writing it back or removing it
+			let entry = T::DbWeight::get().reads_writes(1, 1);
+
+			for (validator, (next, end)) in Backfills::<T>::iter() {
+				if budget.used.saturating_add(entry).any_gt(budget.limit) {
+					return false;
+				}
+				budget.used.saturating_accrue(entry);
+
+				let mut era = next.max(oldest);
+				while era < end.min(active_era) {
+					if !Self::pay_validator(era, &validator, budget) {
+						Backfills::<T>::insert(&validator, (era, end));
+						return false;
+					}
+					era.saturating_inc();
+				}
+				Backfills::<T>::remove(&validator);
+			}
+			true
+		}
+
+		/// Pays out the pages of `validator` in `era` that are not paid out yet, returning whether
+		/// all of them fit in `budget`.
+		fn pay_validator(era: EraIndex, validator: &T::AccountId, budget: &mut Budget) -> bool {
+			// The payee, and its page count and claimed pages in the era
+			let check = T::DbWeight::get().reads(4);
+			let page = <T as pallet_staking::Config>::WeightInfo::payout_stakers_alive_staked(
+				T::MaxExposurePageSize::get(),
+			);
+			if budget.used.saturating_add(check).any_gt(budget.limit) {
+				return false;
+			}
+			budget.used.saturating_accrue(check);
+
+			for _ in 0..Self::unclaimed_pages(era, validator) {
+				if budget.pages >= T::MaxPagesPerBlock::get()
+					|| budget.used.saturating_add(page).any_gt(budget.limit)
+				{
+					return false;
+				}
+				budget.pages.saturating_inc();
+
+				match Self::pay_page(era, validator) {
+					Ok(actual_weight) => {
+						budget.used.saturating_accrue(actual_weight.unwrap_or(page))
+					}
+					Err(error) => {
+						budget.used.saturating_accrue(page);
+						Self::deposit_event(Event::PayoutFailed {
+							validator: validator.clone(),
+							era,
+							error,
+						});
+						break;
+					}
+				}
+			}
+			true
+		}
+
+		/// Pays out the next page of `validator` in `era`, returning its actual weight.
+		fn pay_page(
+			era: EraIndex,
+			validator: &T::AccountId,
+		) -> Result<Option<Weight>, DispatchError> {
+			// Any signed origin can pay out the rewards of a validator.
+			let origin = frame_system::RawOrigin::Signed(validator.clone());
+			// Unlike a dispatch, a direct call keeps the changes of a failed payout.
+			frame_support::storage::with_storage_layer(|| {
+				pallet_staking::Pallet::<T>::payout_stakers(origin.into(), validator.clone(), era)
+			})
+			.map(|info| info.actual_weight)
+			.map_err(|error| error.error)
+		}
+	}
+}
+
+/// Weight and pages used by the payouts of a block, within `limit`.
+struct Budget {
+	limit: Weight,
+	used: Weight,
+	pages: u32,
+}
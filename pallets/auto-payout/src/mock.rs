@@ -0,0 +1,173 @@
+//! Test environment for the auto-payout pallet.
+
+use crate as pallet_auto_payout;
+use frame_election_provider_support::{
+	bounds::{ElectionBounds, ElectionBoundsBuilder},
+	onchain, SequentialPhragmen,
+};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, Hooks},
+};
+use pallet_session::SessionManager;
+use pallet_staking::StakerStatus;
+use sp_runtime::{BuildStorage, Perbill};
+use sp_staking::{currency_to_vote::SaturatingCurrencyToVote, EraIndex};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		Staking: pallet_staking,
+		AutoPayout: pallet_auto_payout,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+	/// Index of the current session, as `pallet_session` would keep it.
+	pub static CurrentSession: u32 = 0;
+}
+
+pub struct OnChainSeqPhragmen;
+
+impl onchain::Config for OnChainSeqPhragmen {
+	type System = Test;
+	type Solver = SequentialPhragmen<u64, Perbill>;
+	type DataProvider = Staking;
+	type WeightInfo = ();
+	type MaxWinners = ConstU32<10>;
+	type Bounds = ElectionsBounds;
+}
+
+pub const ERA_PAYOUT: u64 = 1_000;
+
+/// Pays out `ERA_PAYOUT` to the validators of every era.
+pub struct FixedEraPayout;
+
+impl pallet_staking::EraPayout<u64> for FixedEraPayout {
+	fn era_payout(_total_staked: u64, _total_issuance: u64, _era_duration: u64) -> (u64, u64) {
+		(ERA_PAYOUT, 0)
+	}
+}
+
+impl pallet_staking::Config for Test {
+	type Currency = Balances;
+	type CurrencyBalance = u64;
+	type UnixTime = Timestamp;
+	type CurrencyToVote = SaturatingCurrencyToVote;
+	type RewardRemainder = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = ConstU32<1>;
+	type BondingDuration = ConstU32<3>;
+	type SlashDeferDuration = ConstU32<0>;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type SessionInterface = ();
+	type EraPayout = FixedEraPayout;
+	type NextNewSession = ();
+	type MaxExposurePageSize = ConstU32<2>;
+	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+	type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+	type GenesisElectionProvider = Self::ElectionProvider;
+	type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+	type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+	type TargetList = pallet_staking::UseValidatorsMap<Self>;
+	type MaxUnlockingChunks = ConstU32<32>;
+	type HistoryDepth = ConstU32<84>;
+	type EventListeners = ();
+	type MaxControllersInDeprecationBatch = ConstU32<100>;
+	type WeightInfo = ();
+	type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+}
+
+impl pallet_auto_payout::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxPayees = ConstU32<2>;
+	type MaxPagesPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+/// Ends the active era, in which every validator earned a point, and starts the next one.
+///
+/// Eras last a session, which is rotated as `pallet_session` does.
+pub fn end_era() {
+	let index = CurrentSession::get();
+	Staking::reward_by_ids(pallet_staking::Validators::<Test>::iter_keys().map(|v| (v, 1)));
+	<Staking as SessionManager<u64>>::end_session(index);
+	<Staking as SessionManager<u64>>::start_session(index + 1);
+	<Staking as SessionManager<u64>>::new_session(index + 2);
+	CurrentSession::set(index + 1);
+	Staking::on_finalize(System::block_number());
+}
+
+pub fn active_era() -> EraIndex {
+	pallet_staking::ActiveEra::<Test>::get().unwrap().index
+}
+
+/// Validator 11 is backed by three nominators, so two pages of exposure, and validators 21 and 31
+/// by one each. Era 0 is active.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: [11, 21, 31, 101, 102, 103, 201, 301]
+			.into_iter()
+			.map(|account| (account, 10_000))
+			.collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_staking::GenesisConfig::<Test> {
+		validator_count: 3,
+		minimum_validator_count: 1,
+		stakers: vec![
+			(11, 11, 1_000, StakerStatus::Validator),
+			(21, 21, 1_000, StakerStatus::Validator),
+			(31, 31, 1_000, StakerStatus::Validator),
+			(101, 101, 500, StakerStatus::Nominator(vec![11])),
+			(102, 102, 500, StakerStatus::Nominator(vec![11])),
+			(103, 103, 500, StakerStatus::Nominator(vec![11])),
+			(201, 201, 500, StakerStatus::Nominator(vec![21])),
+			(301, 301, 500, StakerStatus::Nominator(vec![31])),
+		],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		CurrentSession::set(0);
+		<Staking as SessionManager<u64>>::new_session_genesis(0);
+		<Staking as SessionManager<u64>>::new_session_genesis(1);
+		<Staking as SessionManager<u64>>::start_session(0);
+		Staking::on_finalize(1);
+	});
+	ext
+}
@@ -0,0 +1,169 @@
+use crate::{mock::*, Backfills, Error, LastPaid, ScanEra};
+use frame_support::{assert_noop, assert_ok, pallet_prelude::Weight, traits::Hooks};
+use pallet_staking::WeightInfo as _;
+use sp_staking::EraIndex;
+
+fn enable(validator: u64) {
+	assert_ok!(AutoPayout::enable_auto_payout(RuntimeOrigin::signed(
+		validator
+	)));
+}
+
+fn pay_out(limit: Weight) -> Weight {
+	AutoPayout::on_idle(System::block_number(), limit)
+}
+
+fn unclaimed(era: EraIndex) -> u32 {
+	[11, 21]
+		.iter()
+		.map(|validator| AutoPayout::unclaimed_pages(era, validator))
+		.sum()
+}
+
+#[test]
+fn enabling_and_disabling_are_checked() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AutoPayout::enable_auto_payout(RuntimeOrigin::signed(101)),
+			Error::<Test>::NotValidator
+		);
+		enable(11);
+		assert_noop!(
+			AutoPayout::enable_auto_payout(RuntimeOrigin::signed(11)),
+			Error::<Test>::AlreadyEnabled
+		);
+		enable(21);
+		assert_noop!(
+			AutoPayout::enable_auto_payout(RuntimeOrigin::signed(31)),
+			Error::<Test>::TooManyPayees
+		);
+
+		assert_noop!(
+			AutoPayout::disable_auto_payout(RuntimeOrigin::signed(31)),
+			Error::<Test>::NotEnabled
+		);
+		assert_noop!(
+			AutoPayout::remove_payee(RuntimeOrigin::signed(1), 11),
+			Error::<Test>::StillBonded
+		);
+		assert_ok!(AutoPayout::disable_auto_payout(RuntimeOrigin::signed(11)));
+		assert!(!Backfills::<Test>::contains_key(11));
+		enable(31);
+	});
+}
+
+#[test]
+fn payouts_resume_after_the_page_limit() {
+	new_test_ext().execute_with(|| {
+		enable(11);
+		enable(21);
+		// No era ended before they were enabled, so there is nothing to backfill.
+		pay_out(Weight::MAX);
+		assert_eq!(Backfills::<Test>::iter().count(), 0);
+		end_era();
+		end_era();
+		assert_eq!((unclaimed(0), unclaimed(1)), (3, 3));
+
+		// Two pages per block, going through the payees of era 0 before those of era 1.
+		pay_out(Weight::MAX);
+		assert_eq!((unclaimed(0), unclaimed(1)), (1, 3));
+		assert_eq!(ScanEra::<Test>::get(), 0);
+		assert!(LastPaid::<Test>::get().is_some());
+
+		pay_out(Weight::MAX);
+		assert_eq!((unclaimed(0), unclaimed(1)), (0, 2));
+		pay_out(Weight::MAX);
+		assert_eq!((unclaimed(0), unclaimed(1)), (0, 0));
+		assert_eq!(ScanEra::<Test>::get(), active_era());
+		assert_eq!(LastPaid::<Test>::get(), None);
+		assert_eq!(AutoPayout::backlog(), 0);
+
+		// Validator 31 is not a payee.
+		assert_eq!(AutoPayout::unclaimed_eras(&31), vec![0, 1]);
+	});
+}
+
+#[test]
+fn removed_payees_are_skipped() {
+	new_test_ext().execute_with(|| {
+		enable(11);
+		enable(21);
+		// No era ended before they were enabled, so there is nothing to backfill.
+		pay_out(Weight::MAX);
+		assert_eq!(Backfills::<Test>::iter().count(), 0);
+		end_era();
+
+		// Whichever payee comes first, the page limit stops the payouts before the other one is
+		// done.
+		pay_out(Weight::MAX);
+		let removed = LastPaid::<Test>::get().unwrap();
+		let other = if removed == 11 { 21 } else { 11 };
+		assert_eq!(AutoPayout::unclaimed_pages(0, &removed), 0);
+		assert!(AutoPayout::unclaimed_pages(0, &other) > 0);
+
+		assert_ok!(AutoPayout::disable_auto_payout(RuntimeOrigin::signed(
+			removed
+		)));
+		pay_out(Weight::MAX);
+		assert_eq!(AutoPayout::unclaimed_pages(0, &other), 0);
+		assert_eq!(ScanEra::<Test>::get(), 1);
+
+		end_era();
+		pay_out(Weight::MAX);
+		pay_out(Weight::MAX);
+		assert_eq!(AutoPayout::unclaimed_eras(&other), vec![]);
+		assert_eq!(AutoPayout::unclaimed_eras(&removed), vec![1]);
+	});
+}
+
+#[test]
+fn payouts_stay_within_the_weight_limit() {
+	new_test_ext().execute_with(|| {
+		enable(11);
+		end_era();
+		let page = <() as pallet_staking::WeightInfo>::payout_stakers_alive_staked(2);
+
+		let limit = page.saturating_sub(Weight::from_parts(1, 0));
+		assert!(pay_out(limit).all_lte(limit));
+		assert_eq!(AutoPayout::unclaimed_pages(0, &11), 2);
+
+		assert!(pay_out(page).all_lte(page));
+		assert_eq!(AutoPayout::unclaimed_pages(0, &11), 1);
+
+		assert_eq!(pay_out(Weight::zero()), Weight::zero());
+		assert_eq!(AutoPayout::unclaimed_pages(0, &11), 1);
+
+		pay_out(Weight::MAX);
+		assert_eq!(AutoPayout::unclaimed_pages(0, &11), 0);
+	});
+}
+
+#[test]
+fn new_payees_are_backfilled_without_restarting_the_payouts() {
+	new_test_ext().execute_with(|| {
+		enable(11);
+		end_era();
+		end_era();
+		pay_out(Weight::MAX);
+		pay_out(Weight::MAX);
+		assert_eq!(AutoPayout::unclaimed_eras(&11), vec![]);
+		assert_eq!(ScanEra::<Test>::get(), 2);
+
+		enable(21);
+		assert_eq!(ScanEra::<Test>::get(), 2);
+		assert_eq!(Backfills::<Test>::get(21), Some((0, 3)));
+		assert_eq!(AutoPayout::backlog(), 2);
+
+		pay_out(Weight::MAX);
+		assert_eq!(AutoPayout::unclaimed_eras(&21), vec![]);
+		assert_eq!(Backfills::<Test>::get(21), None);
+		assert_eq!(ScanEra::<Test>::get(), 2);
+		assert_eq!(AutoPayout::backlog(), 0);
+
+		// From then on, the payouts of all payees include it.
+		end_era();
+		pay_out(Weight::MAX);
+		pay_out(Weight::MAX);
+		assert_eq!(unclaimed(2), 0);
+	});
+}
@@ -0,0 +1,92 @@
+//! Weights for `pallet_auto_payout`.
+//!
+//! The calls only read a few storage items of this pallet and of staking before writing them.
+//! Their weights are those accesses on top of a fixed base, placeholders until the benchmarks of
+//! `benchmarking.rs` are run on the reference hardware, with a node built with the
+//! `runtime-benchmarks` feature:
+//!
+//! ```text
+//! impetus-node benchmark pallet --chain dev --pallet pallet_auto_payout --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/auto-payout/src/weights.rs
+//! ```
+//!
+//! The payouts made in `on_idle` are weighed with the staking weights.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_auto_payout`.
+pub trait WeightInfo {
+	fn enable_auto_payout() -> Weight;
+	fn disable_auto_payout() -> Weight;
+	fn remove_payee() -> Weight;
+}
+
+/// Weights for `pallet_auto_payout` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::ScanEra` (r:1 w:0)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn enable_auto_payout() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn disable_auto_payout() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn remove_payee() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::ScanEra` (r:1 w:0)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn enable_auto_payout() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn disable_auto_payout() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `AutoPayout::Payees` (r:1 w:1)
+	/// Storage: `AutoPayout::CounterForPayees` (r:1 w:1)
+	/// Storage: `AutoPayout::Backfills` (r:0 w:1)
+	fn remove_payee() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -0,0 +1,206 @@
+//! Inflation of the staking eras, with parameters set by governance.
+//!
+//! Each era mints `annual_inflation` of the total issuance, prorated by the duration of the era,
+//! and splits it between the stakers and the treasury:
+//!
+//! - `treasury_fraction` of it always goes to the treasury,
+//! - stakers get the rest of `min_inflation` whatever the stake, and the rest of the inflation
+//!   above it when `ideal_stake` of the total issuance is staked, and less the further the stake
+//!   is from it: proportionally below it, and decaying by half every `falloff` above it,
+//! - the treasury gets what stakers do not.
+//!
+//! The pallet is the `EraPayout` of staking, whose `RewardRemainder` receives the treasury part.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+use pallet_staking_reward_fn::compute_inflation;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, One, Zero},
+	FixedPointNumber, FixedU128, Perquintill, SaturatedConversion, Saturating,
+};
+
+/// Milliseconds in a Julian year, the period of the annual inflation.
+pub const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+/// Parameters of the inflation.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct InflationParams {
+	/// Share of the total issuance minted over a year.
+	pub annual_inflation: Perquintill,
+	/// Share of the total issuance minted over a year for stakers whatever the stake, out of the
+	/// annual inflation.
+	pub min_inflation: Perquintill,
+	/// Share of the inflation that goes to the treasury whatever the stake.
+	pub treasury_fraction: Perquintill,
+	/// Share of the total issuance staked at which stakers get all the inflation left after the
+	/// treasury fraction, `min_inflation` included.
+	pub ideal_stake: Perquintill,
+	/// Stake above `ideal_stake` over which the share of stakers halves.
+	pub falloff: Perquintill,
+}
+
+impl InflationParams {
+	/// Share of the inflation that goes to stakers when `stake` of the total issuance is staked.
+	pub fn staking_share(&self, stake: Perquintill) -> Perquintill {
+		let min = Perquintill::from_rational(
+			self.min_inflation.deconstruct(),
+			self.annual_inflation.deconstruct(),
+		);
+		let above_min = Perquintill::one().saturating_sub(min)
+			* compute_inflation(stake, self.ideal_stake, self.falloff);
+
+		Perquintill::one().saturating_sub(self.treasury_fraction) * min.saturating_add(above_min)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to change the inflation parameters.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Parameters of the inflation until the admin origin changes them.
+		#[pallet::constant]
+		type DefaultParams: Get<InflationParams>;
+
+		/// Maximum annual inflation that the admin origin can set.
+		#[pallet::constant]
+		type MaxAnnualInflation: Get<Perquintill>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Parameters of the inflation.
+	#[pallet::storage]
+	pub type Params<T: Config> = StorageValue<_, InflationParams, ValueQuery, T::DefaultParams>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The inflation parameters changed, from the payout of the current era.
+		ParamsChanged { params: InflationParams },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The annual inflation is above the maximum.
+		InflationTooHigh,
+		/// The ideal stake is zero.
+		ZeroIdealStake,
+		/// The falloff is below 1%, which the inflation curve does not support.
+		FalloffTooLow,
+		/// The minimum inflation is above the annual inflation.
+		MinInflationTooHigh,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sets the parameters of the inflation.
+		///
+		/// The dispatch origin must be the admin origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_params())]
+		pub fn set_params(origin: OriginFor<T>, params: InflationParams) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				params.annual_inflation <= T::MaxAnnualInflation::get(),
+				Error::<T>::InflationTooHigh
+			);
+			ensure!(
+				params.min_inflation <= params.annual_inflation,
+				Error::<T>::MinInflationTooHigh
+			);
+			ensure!(!params.ideal_stake.is_zero(), Error::<T>::ZeroIdealStake);
+			ensure!(
+				params.falloff >= Perquintill::from_percent(1),
+				Error::<T>::FalloffTooLow
+			);
+
+			Params::<T>::put(params);
+
+			Self::deposit_event(Event::ParamsChanged { params });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Payouts to stakers and to the treasury of an era of `era_duration_millis`.
+		pub fn era_payouts(
+			total_staked: u128,
+			total_issuance: u128,
+			era_duration_millis: u64,
+		) -> (u128, u128) {
+			let params = Params::<T>::get();
+			let period = Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR);
+			let inflation = period * (params.annual_inflation * total_issuance);
+			let stake = Perquintill::from_rational(total_staked, total_issuance);
+			let staking = params.staking_share(stake) * inflation;
+
+			(staking, inflation.saturating_sub(staking))
+		}
+
+		/// Projected yearly return of the stake, before commission, for eras of
+		/// `era_duration_millis`.
+		///
+		/// It assumes that the parameters, the stake and the total issuance stay as they are, and
+		/// that rewards are staked again every era.
+		pub fn projected_apy(
+			total_staked: u128,
+			total_issuance: u128,
+			era_duration_millis: u64,
+		) -> FixedU128 {
+			if total_staked.is_zero() || era_duration_millis.is_zero() {
+				return FixedU128::zero();
+			}
+			let (staking, _) = Self::era_payouts(total_staked, total_issuance, era_duration_millis);
+			let era_return = FixedU128::saturating_from_rational(staking, total_staked);
+			let eras_per_year = MILLISECONDS_PER_YEAR / era_duration_millis;
+
+			FixedU128::one()
+				.saturating_add(era_return)
+				.saturating_pow(eras_per_year as usize)
+				.saturating_sub(FixedU128::one())
+		}
+	}
+}
+
+impl<T: Config, Balance: AtLeast32BitUnsigned> pallet_staking::EraPayout<Balance> for Pallet<T> {
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		let (staking, treasury) = Self::era_payouts(
+			total_staked.saturated_into(),
+			total_issuance.saturated_into(),
+			era_duration_millis,
+		);
+
+		(staking.saturated_into(), treasury.saturated_into())
+	}
+}
@@ -0,0 +1,49 @@
+//! Test environment for the inflation pallet.
+
+use crate as pallet_inflation;
+use crate::InflationParams;
+use frame_support::{derive_impl, parameter_types};
+use sp_runtime::{BuildStorage, Perquintill};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Inflation: pallet_inflation,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const DefaultParams: InflationParams = InflationParams {
+		annual_inflation: Perquintill::from_percent(10),
+		min_inflation: Perquintill::zero(),
+		treasury_fraction: Perquintill::zero(),
+		ideal_stake: Perquintill::from_percent(50),
+		falloff: Perquintill::from_percent(5),
+	};
+	pub const MaxAnnualInflation: Perquintill = Perquintill::from_percent(20);
+}
+
+impl pallet_inflation::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type DefaultParams = DefaultParams;
+	type MaxAnnualInflation = MaxAnnualInflation;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,180 @@
+use crate::{mock::*, Error, Event, InflationParams, Params, MILLISECONDS_PER_YEAR};
+use frame_support::{assert_noop, assert_ok};
+use pallet_staking::EraPayout;
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, Perquintill};
+
+const ISSUANCE: u128 = 1_000_000_000;
+
+fn payouts(staked: u128, era_duration_millis: u64) -> (u128, u128) {
+	Inflation::era_payouts(staked, ISSUANCE, era_duration_millis)
+}
+
+fn set_params(params: InflationParams) {
+	assert_ok!(Inflation::set_params(RuntimeOrigin::root(), params));
+}
+
+#[test]
+fn era_payouts_follow_the_stake() {
+	new_test_ext().execute_with(|| {
+		// 10% a year, all of it to stakers at 50% staked, less below and above.
+		assert_eq!(
+			payouts(ISSUANCE / 2, MILLISECONDS_PER_YEAR),
+			(100_000_000, 0)
+		);
+		assert_eq!(
+			payouts(ISSUANCE / 4, MILLISECONDS_PER_YEAR),
+			(50_000_000, 50_000_000)
+		);
+		assert_eq!(payouts(0, MILLISECONDS_PER_YEAR), (0, 100_000_000));
+
+		// The share of stakers halves every 5% above the ideal stake.
+		let (staking, treasury) = payouts(ISSUANCE * 6 / 10, MILLISECONDS_PER_YEAR);
+		assert!(staking.abs_diff(25_000_000) < 1_000);
+		assert_eq!(staking + treasury, 100_000_000);
+
+		// Eras get their share of the year.
+		assert_eq!(
+			payouts(ISSUANCE / 4, MILLISECONDS_PER_YEAR / 2),
+			(25_000_000, 25_000_000)
+		);
+		assert_eq!(payouts(ISSUANCE / 4, 0), (0, 0));
+
+		set_params(InflationParams {
+			treasury_fraction: Perquintill::from_percent(20),
+			..DefaultParams::get()
+		});
+		assert_eq!(
+			payouts(ISSUANCE / 2, MILLISECONDS_PER_YEAR),
+			(80_000_000, 20_000_000)
+		);
+		assert_eq!(
+			<Inflation as EraPayout<u64>>::era_payout(
+				ISSUANCE as u64 / 2,
+				ISSUANCE as u64,
+				MILLISECONDS_PER_YEAR
+			),
+			(80_000_000, 20_000_000)
+		);
+	});
+}
+
+#[test]
+fn min_inflation_goes_to_stakers_whatever_the_stake() {
+	new_test_ext().execute_with(|| {
+		set_params(InflationParams {
+			min_inflation: Perquintill::from_percent(2),
+			..DefaultParams::get()
+		});
+		// 2% of the 10% go to stakers, and the rest of it along the stake as before.
+		assert_eq!(payouts(0, MILLISECONDS_PER_YEAR), (20_000_000, 80_000_000));
+		assert_eq!(
+			payouts(ISSUANCE / 4, MILLISECONDS_PER_YEAR),
+			(60_000_000, 40_000_000)
+		);
+		assert_eq!(
+			payouts(ISSUANCE / 2, MILLISECONDS_PER_YEAR),
+			(100_000_000, 0)
+		);
+
+		// The treasury fraction applies to the minimum inflation too.
+		set_params(InflationParams {
+			min_inflation: Perquintill::from_percent(2),
+			treasury_fraction: Perquintill::from_percent(20),
+			..DefaultParams::get()
+		});
+		assert_eq!(payouts(0, MILLISECONDS_PER_YEAR), (16_000_000, 84_000_000));
+	});
+}
+
+#[test]
+fn projected_apy_compounds_the_era_payouts() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Inflation::projected_apy(0, ISSUANCE, MILLISECONDS_PER_YEAR),
+			FixedU128::default()
+		);
+		assert_eq!(
+			Inflation::projected_apy(ISSUANCE / 2, ISSUANCE, 0),
+			FixedU128::default()
+		);
+
+		// Stakers of half the issuance get 10% of it a year, 20% of their stake.
+		assert_eq!(
+			Inflation::projected_apy(ISSUANCE / 2, ISSUANCE, MILLISECONDS_PER_YEAR),
+			FixedU128::from_rational(1, 5)
+		);
+		// Staking again the 5% of quarterly eras.
+		assert_eq!(
+			Inflation::projected_apy(ISSUANCE / 2, ISSUANCE, MILLISECONDS_PER_YEAR / 4),
+			FixedU128::from_rational(21_550_625, 100_000_000)
+		);
+
+		set_params(InflationParams {
+			treasury_fraction: Perquintill::one(),
+			..DefaultParams::get()
+		});
+		assert_eq!(
+			Inflation::projected_apy(ISSUANCE / 2, ISSUANCE, MILLISECONDS_PER_YEAR),
+			FixedU128::default()
+		);
+	});
+}
+
+#[test]
+fn set_params_checks_the_origin_and_bounds() {
+	new_test_ext().execute_with(|| {
+		let params = InflationParams {
+			annual_inflation: MaxAnnualInflation::get(),
+			falloff: Perquintill::from_percent(1),
+			..DefaultParams::get()
+		};
+		assert_noop!(
+			Inflation::set_params(RuntimeOrigin::signed(1), params),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Inflation::set_params(
+				RuntimeOrigin::root(),
+				InflationParams {
+					annual_inflation: Perquintill::from_percent(21),
+					..params
+				}
+			),
+			Error::<Test>::InflationTooHigh
+		);
+		assert_noop!(
+			Inflation::set_params(
+				RuntimeOrigin::root(),
+				InflationParams {
+					min_inflation: Perquintill::from_percent(21),
+					..params
+				}
+			),
+			Error::<Test>::MinInflationTooHigh
+		);
+		assert_noop!(
+			Inflation::set_params(
+				RuntimeOrigin::root(),
+				InflationParams {
+					ideal_stake: Perquintill::zero(),
+					..params
+				}
+			),
+			Error::<Test>::ZeroIdealStake
+		);
+		assert_noop!(
+			Inflation::set_params(
+				RuntimeOrigin::root(),
+				InflationParams {
+					falloff: Perquintill::from_rational(99u64, 10_000),
+					..params
+				}
+			),
+			Error::<Test>::FalloffTooLow
+		);
+
+		set_params(params);
+		assert_eq!(Params::<Test>::get(), params);
+		System::assert_last_event(Event::ParamsChanged { params }.into());
+	});
+}
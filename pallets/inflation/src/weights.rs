@@ -0,0 +1,41 @@
+//! Weights for `pallet_inflation`.
+//!
+//! `set_params` only checks the origin and the parameters before writing one storage item. Its
+//! weight is that write on top of a fixed base until the benchmarks of `benchmarking.rs` are run
+//! on the reference hardware, with a node built with the `runtime-benchmarks` feature:
+//!
+//! ```text
+//! impetus-node benchmark pallet --chain dev --pallet pallet_inflation --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/inflation/src/weights.rs
+//! ```
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_inflation`.
+pub trait WeightInfo {
+	fn set_params() -> Weight;
+}
+
+/// Weights for `pallet_inflation` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Inflation::Params` (r:0 w:1)
+	fn set_params() -> Weight {
+		Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Inflation::Params` (r:0 w:1)
+	fn set_params() -> Weight {
+		Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,38 @@
+//! Benchmarks for the precompile registry pallet.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Contains;
+use sp_core::H160;
+
+/// The first address of a precompile that the registry manages.
+fn managed_precompile<T: Config>() -> Result<H160, BenchmarkError> {
+	(0..=u64::from(u16::MAX))
+		.map(H160::from_low_u64_be)
+		.find(T::Precompiles::contains)
+		.ok_or(BenchmarkError::Weightless)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_status() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let address = managed_precompile::<T>()?;
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			address,
+			PrecompileStatus::Disabled,
+		);
+
+		assert_eq!(Statuses::<T>::get(address), PrecompileStatus::Disabled);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
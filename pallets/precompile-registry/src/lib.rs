@@ -0,0 +1,143 @@
+//! Registry of the status of the runtime's precompiles.
+//!
+//! The precompiles are compiled into the runtime, but each of their addresses has a status that
+//! the manager origin can change without a runtime upgrade:
+//!
+//! - `Enabled`, the default, runs the precompile,
+//! - `Deprecated` still runs it, and tells contracts and wallets that it is going away,
+//! - `Disabled` makes every call to it revert, to stop a faulty precompile quickly.
+//!
+//! A disabled precompile is still reported as a precompile, so that its address keeps behaving
+//! as reserved instead of as an empty account whose calls succeed.
+//!
+//! The runtime chooses which of its precompiles the registry manages, leaving out those that must
+//! always run, such as the Ethereum ones.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+
+/// Status of a precompile.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum PrecompileStatus {
+	/// Calls run the precompile.
+	#[default]
+	Enabled,
+	/// Calls still run the precompile, which is due to be disabled or removed.
+	Deprecated,
+	/// Calls to the precompile revert.
+	Disabled,
+}
+
+impl PrecompileStatus {
+	/// Whether calls run the precompile.
+	pub fn is_active(&self) -> bool {
+		!matches!(self, Self::Disabled)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::traits::Contains;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to change the status of a precompile.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Addresses of the precompiles whose status the registry manages.
+		type Precompiles: Contains<H160>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Status of the precompiles that are not enabled.
+	#[pallet::storage]
+	pub type Statuses<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, PrecompileStatus, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The status of the precompile at `address` changed.
+		StatusChanged {
+			address: H160,
+			status: PrecompileStatus,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There is no precompile managed by the registry at this address.
+		NotAPrecompile,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sets the status of the precompile at `address`.
+		///
+		/// The dispatch origin must be the manager origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_status())]
+		pub fn set_status(
+			origin: OriginFor<T>,
+			address: H160,
+			status: PrecompileStatus,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::Precompiles::contains(&address),
+				Error::<T>::NotAPrecompile
+			);
+
+			if status == PrecompileStatus::default() {
+				Statuses::<T>::remove(address);
+			} else {
+				Statuses::<T>::insert(address, status);
+			}
+
+			Self::deposit_event(Event::StatusChanged { address, status });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Status of the precompile at `address`, `Enabled` if there is no precompile there.
+		pub fn status(address: H160) -> PrecompileStatus {
+			Statuses::<T>::get(address)
+		}
+
+		/// Whether there is a precompile managed by the registry at `address`, whatever its status.
+		pub fn is_precompile(address: H160) -> bool {
+			T::Precompiles::contains(&address)
+		}
+
+		/// Whether there is a precompile at `address` that calls run.
+		pub fn is_active_precompile(address: H160) -> bool {
+			Self::is_precompile(address) && Self::status(address).is_active()
+		}
+	}
+}
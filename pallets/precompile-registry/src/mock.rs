@@ -0,0 +1,55 @@
+//! Test environment for the precompile registry pallet.
+
+use crate as pallet_precompile_registry;
+use frame_support::{derive_impl, ord_parameter_types, traits::Contains};
+use frame_system::EnsureSignedBy;
+use sp_core::H160;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		PrecompileRegistry: pallet_precompile_registry,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+ord_parameter_types! {
+	pub const Manager: u64 = 1;
+}
+
+/// Precompiles at 0x400 to 0x402.
+pub struct Precompiles;
+
+impl Contains<H160> for Precompiles {
+	fn contains(address: &H160) -> bool {
+		(precompile(0)..=precompile(2)).contains(address)
+	}
+}
+
+/// Address of the `index`th precompile.
+pub fn precompile(index: u64) -> H160 {
+	H160::from_low_u64_be(0x400 + index)
+}
+
+impl pallet_precompile_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureSignedBy<Manager, u64>;
+	type Precompiles = Precompiles;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
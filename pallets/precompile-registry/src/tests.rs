@@ -0,0 +1,94 @@
+use crate::{mock::*, Error, Event, PrecompileStatus, Statuses};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H160;
+use sp_runtime::DispatchError;
+
+#[test]
+fn only_the_manager_sets_a_status() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PrecompileRegistry::set_status(
+				RuntimeOrigin::signed(2),
+				precompile(0),
+				PrecompileStatus::Disabled
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PrecompileRegistry::set_status(
+				RuntimeOrigin::root(),
+				precompile(0),
+				PrecompileStatus::Disabled
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn only_managed_precompiles_have_a_status() {
+	new_test_ext().execute_with(|| {
+		for address in [H160::from_low_u64_be(1), precompile(3)] {
+			assert_noop!(
+				PrecompileRegistry::set_status(
+					RuntimeOrigin::signed(1),
+					address,
+					PrecompileStatus::Disabled
+				),
+				Error::<Test>::NotAPrecompile
+			);
+			assert!(!PrecompileRegistry::is_precompile(address));
+			assert!(!PrecompileRegistry::is_active_precompile(address));
+		}
+	});
+}
+
+#[test]
+fn statuses_round_trip() {
+	new_test_ext().execute_with(|| {
+		let address = precompile(1);
+		assert_eq!(
+			PrecompileRegistry::status(address),
+			PrecompileStatus::Enabled
+		);
+		assert!(PrecompileRegistry::is_active_precompile(address));
+
+		for status in [
+			PrecompileStatus::Deprecated,
+			PrecompileStatus::Disabled,
+			PrecompileStatus::Enabled,
+		] {
+			assert_ok!(PrecompileRegistry::set_status(
+				RuntimeOrigin::signed(1),
+				address,
+				status
+			));
+			System::assert_last_event(Event::StatusChanged { address, status }.into());
+
+			assert_eq!(PrecompileRegistry::status(address), status);
+			assert!(PrecompileRegistry::is_precompile(address));
+			assert_eq!(
+				PrecompileRegistry::is_active_precompile(address),
+				status != PrecompileStatus::Disabled
+			);
+		}
+
+		// Enabled is the default, which is not stored.
+		assert!(!Statuses::<Test>::contains_key(address));
+	});
+}
+
+#[test]
+fn statuses_are_per_precompile() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PrecompileRegistry::set_status(
+			RuntimeOrigin::signed(1),
+			precompile(0),
+			PrecompileStatus::Disabled
+		));
+
+		assert!(!PrecompileRegistry::is_active_precompile(precompile(0)));
+		assert!(PrecompileRegistry::is_active_precompile(precompile(2)));
+		assert_eq!(Statuses::<Test>::iter().count(), 1);
+	});
+}
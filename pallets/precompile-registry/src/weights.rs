@@ -0,0 +1,42 @@
+//! Weights for `pallet_precompile_registry`.
+//!
+//! `set_status` only checks the origin and the address before writing one storage item. Its
+//! weight is that write on top of a fixed base, a placeholder until the benchmarks of
+//! `benchmarking.rs` are run on the reference hardware, with a node built with the
+//! `runtime-benchmarks` feature:
+//!
+//! ```text
+//! impetus-node benchmark pallet --chain dev --pallet pallet_precompile_registry --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/precompile-registry/src/weights.rs
+//! ```
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_precompile_registry`.
+pub trait WeightInfo {
+	fn set_status() -> Weight;
+}
+
+/// Weights for `pallet_precompile_registry` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `PrecompileRegistry::Statuses` (r:0 w:1)
+	fn set_status() -> Weight {
+		Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `PrecompileRegistry::Statuses` (r:0 w:1)
+	fn set_status() -> Weight {
+		Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
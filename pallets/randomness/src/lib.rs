@@ -0,0 +1,412 @@
+//! Randomness from BABE for the EVM.
+//!
+//! Two sources are exposed:
+//!
+//! - the epoch randomness of BABE, for the current epoch and the one before it, which everyone
+//!   knows from the start of the epoch,
+//! - the VRF output of the author of a future block, through requests that reserve a deposit.
+//!
+//! A request targets the block `delay` blocks after it, and is fulfilled with the first VRF
+//! output revealed at or after that block: blocks of secondary slots carry no VRF output. Once
+//! the output is revealed, the requester can fulfil its request once, which returns its random
+//! words and unreserves its deposit. A request that is not fulfilled within `Expiration` blocks
+//! of its target can no longer be, and anyone can purge it to receive its deposit.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{BalanceStatus, Currency, ReservableCurrency},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
+use sp_runtime::{traits::One, Saturating};
+use sp_std::vec::Vec;
+
+/// Identifier of a randomness request.
+pub type RequestId = u64;
+
+/// Balance of the deposit currency.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A request for random words from the VRF output of a future block.
+#[derive(Clone, PartialEq, Eq)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct Request<AccountId, Balance, BlockNumber> {
+	/// Account that made the request, the only one that can fulfil it.
+	pub requester: AccountId,
+	/// Deposit reserved from the requester.
+	pub deposit: Balance,
+	/// Mixed into the random words, so that requests on the same block get different ones.
+	pub salt: H256,
+	/// Number of random words to return.
+	pub num_words: u8,
+	/// Block from which the VRF output is taken.
+	pub target: BlockNumber,
+}
+
+/// Status of a request.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum RequestStatus {
+	/// There is no request with this identifier.
+	DoesNotExist,
+	/// The VRF output of the target block is not revealed yet.
+	Pending,
+	/// The requester can fulfil the request.
+	Ready,
+	/// Anyone can purge the request.
+	Expired,
+}
+
+/// VRF output that the requests on a target block wait for.
+#[derive(Clone, Default, PartialEq, Eq)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct RandomnessResult {
+	/// First VRF output revealed at or after the target block.
+	pub randomness: Option<[u8; 32]>,
+	/// Number of requests on the target block that are neither fulfilled nor purged.
+	pub requests: u32,
+}
+
+/// BABE epoch randomness, as of the last epoch change.
+#[derive(Clone, Default, PartialEq, Eq)]
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct EpochRandomness {
+	/// Index of the current epoch.
+	pub epoch_index: u64,
+	/// Randomness of the current epoch.
+	pub current: [u8; 32],
+	/// Randomness of the previous epoch.
+	pub previous: [u8; 32],
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_babe::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency in which deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved from the requester until its request is fulfilled or purged.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+
+		/// Minimum number of blocks between a request and its target block.
+		#[pallet::constant]
+		type MinBlockDelay: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of blocks between a request and its target block.
+		#[pallet::constant]
+		type MaxBlockDelay: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after its target block from which a request expires.
+		#[pallet::constant]
+		type Expiration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of random words of a request.
+		#[pallet::constant]
+		type MaxRandomWords: Get<u8>;
+
+		/// Maximum number of target blocks waiting for a VRF output, which bounds the work of
+		/// `on_initialize`. Should be above `MaxBlockDelay`.
+		#[pallet::constant]
+		type MaxTargetBlocks: Get<u32>;
+	}
+
+	/// Identifier of the next request.
+	#[pallet::storage]
+	pub type NextRequestId<T> = StorageValue<_, RequestId, ValueQuery>;
+
+	/// Requests that are neither fulfilled nor purged.
+	#[pallet::storage]
+	pub type Requests<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		RequestId,
+		Request<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// VRF output of each target block with requests.
+	#[pallet::storage]
+	pub type Results<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, RandomnessResult>;
+
+	/// Target blocks whose VRF output is not revealed yet, in ascending order.
+	#[pallet::storage]
+	pub type AwaitingBlocks<T: Config> =
+		StorageValue<_, BoundedVec<BlockNumberFor<T>, T::MaxTargetBlocks>, ValueQuery>;
+
+	/// Last VRF output seen.
+	///
+	/// BABE keeps the output of the last block that had one, so an output equal to this one was
+	/// already revealed and must not fulfil requests again.
+	#[pallet::storage]
+	pub type LastVrfOutput<T> = StorageValue<_, [u8; 32]>;
+
+	/// BABE randomness of the current and previous epochs.
+	#[pallet::storage]
+	pub type Epoch<T> = StorageValue<_, EpochRandomness, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `requester` requested randomness from the VRF output of `target`.
+		RandomnessRequested {
+			id: RequestId,
+			requester: T::AccountId,
+			target: BlockNumberFor<T>,
+		},
+		/// The request was fulfilled and its deposit unreserved.
+		RequestFulfilled { id: RequestId },
+		/// The expired request was purged and its deposit transferred to `purger`.
+		RequestPurged { id: RequestId, purger: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The delay is outside of the allowed range.
+		InvalidDelay,
+		/// The number of random words is zero or above the maximum.
+		InvalidNumberOfWords,
+		/// Too many target blocks are waiting for a VRF output.
+		TooManyTargetBlocks,
+		/// The requester cannot pay the deposit.
+		CannotReserveDeposit,
+		/// There is no request with this identifier.
+		RequestNotFound,
+		/// Only the requester can fulfil its request.
+		NotRequester,
+		/// The VRF output of the target block is not revealed yet.
+		RandomnessNotReady,
+		/// The request expired and can only be purged.
+		RequestExpired,
+		/// The request has not expired.
+		RequestNotExpired,
+		/// The deposit cannot be transferred to the purger.
+		CannotTransferDeposit,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// EpochIndex, Epoch, AuthorVrfRandomness, LastVrfOutput
+			let mut weight = T::DbWeight::get().reads(4);
+
+			let epoch_index = pallet_babe::Pallet::<T>::epoch_index();
+			let epoch = Epoch::<T>::get();
+			if epoch.epoch_index != epoch_index {
+				Epoch::<T>::put(EpochRandomness {
+					epoch_index,
+					current: pallet_babe::Pallet::<T>::randomness(),
+					previous: epoch.current,
+				});
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+
+			// BABE sets the output of a block when finalizing it.
+			if let Some(output) = pallet_babe::Pallet::<T>::author_vrf_randomness() {
+				if LastVrfOutput::<T>::get() != Some(output) {
+					LastVrfOutput::<T>::put(output);
+					weight.saturating_accrue(T::DbWeight::get().writes(1));
+					weight.saturating_accrue(Self::reveal(now.saturating_sub(One::one()), output));
+				}
+			}
+
+			weight
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Requests `num_words` random words from the VRF output of the block `delay` blocks from
+		/// now, and reserves the deposit of `requester`.
+		pub fn request_randomness(
+			requester: T::AccountId,
+			salt: H256,
+			num_words: u8,
+			delay: BlockNumberFor<T>,
+		) -> Result<RequestId, Error<T>> {
+			ensure!(
+				(T::MinBlockDelay::get()..=T::MaxBlockDelay::get()).contains(&delay),
+				Error::<T>::InvalidDelay
+			);
+			ensure!(
+				(1..=T::MaxRandomWords::get()).contains(&num_words),
+				Error::<T>::InvalidNumberOfWords
+			);
+
+			let target = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+			let mut result = Results::<T>::get(target).unwrap_or_default();
+			let mut awaiting = AwaitingBlocks::<T>::get();
+			if result.requests == 0 {
+				let index = awaiting.partition_point(|block| *block < target);
+				awaiting
+					.try_insert(index, target)
+					.map_err(|_| Error::<T>::TooManyTargetBlocks)?;
+			}
+
+			let deposit = T::Deposit::get();
+			T::Currency::reserve(&requester, deposit)
+				.map_err(|_| Error::<T>::CannotReserveDeposit)?;
+
+			let id = NextRequestId::<T>::get();
+			NextRequestId::<T>::put(id.saturating_add(1));
+			Requests::<T>::insert(
+				id,
+				Request {
+					requester: requester.clone(),
+					deposit,
+					salt,
+					num_words,
+					target,
+				},
+			);
+			result.requests.saturating_inc();
+			Results::<T>::insert(target, result);
+			AwaitingBlocks::<T>::put(awaiting);
+
+			Self::deposit_event(Event::RandomnessRequested {
+				id,
+				requester,
+				target,
+			});
+			Ok(id)
+		}
+
+		/// Returns the random words of the request `id` of `requester`, removes the request and
+		/// unreserves its deposit.
+		pub fn fulfil_request(
+			requester: &T::AccountId,
+			id: RequestId,
+		) -> Result<Vec<H256>, Error<T>> {
+			let request = Requests::<T>::get(id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.requester == *requester, Error::<T>::NotRequester);
+			ensure!(!Self::is_expired(&request), Error::<T>::RequestExpired);
+			let randomness = Results::<T>::get(request.target)
+				.and_then(|result| result.randomness)
+				.ok_or(Error::<T>::RandomnessNotReady)?;
+
+			let words = (0..request.num_words)
+				.map(|index| random_word(&randomness, &request.salt, id, index))
+				.collect();
+
+			T::Currency::unreserve(&request.requester, request.deposit);
+			Self::remove_request(id, &request);
+
+			Self::deposit_event(Event::RequestFulfilled { id });
+			Ok(words)
+		}
+
+		/// Removes the expired request `id` and transfers its deposit to `purger`.
+		pub fn purge_expired_request(purger: T::AccountId, id: RequestId) -> Result<(), Error<T>> {
+			let request = Requests::<T>::get(id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(Self::is_expired(&request), Error::<T>::RequestNotExpired);
+
+			T::Currency::repatriate_reserved(
+				&request.requester,
+				&purger,
+				request.deposit,
+				BalanceStatus::Free,
+			)
+			.map_err(|_| Error::<T>::CannotTransferDeposit)?;
+			Self::remove_request(id, &request);
+
+			Self::deposit_event(Event::RequestPurged { id, purger });
+			Ok(())
+		}
+
+		/// Status of the request `id`.
+		pub fn request_status(id: RequestId) -> RequestStatus {
+			let Some(request) = Requests::<T>::get(id) else {
+				return RequestStatus::DoesNotExist;
+			};
+			if Self::is_expired(&request) {
+				RequestStatus::Expired
+			} else if Results::<T>::get(request.target)
+				.and_then(|result| result.randomness)
+				.is_some()
+			{
+				RequestStatus::Ready
+			} else {
+				RequestStatus::Pending
+			}
+		}
+
+		/// BABE randomness of the current epoch.
+		pub fn current_epoch_randomness() -> [u8; 32] {
+			Epoch::<T>::get().current
+		}
+
+		/// BABE randomness of the previous epoch.
+		pub fn previous_epoch_randomness() -> [u8; 32] {
+			Epoch::<T>::get().previous
+		}
+
+		fn is_expired(request: &Request<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) -> bool {
+			frame_system::Pallet::<T>::block_number()
+				> request.target.saturating_add(T::Expiration::get())
+		}
+
+		fn remove_request(
+			id: RequestId,
+			request: &Request<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		) {
+			Requests::<T>::remove(id);
+			Results::<T>::mutate_exists(request.target, |result| {
+				if let Some(pending) = result {
+					pending.requests.saturating_dec();
+					if pending.requests == 0 {
+						*result = None;
+					}
+				}
+			});
+		}
+
+		/// Sets `output`, revealed at block `revealed_at`, as the VRF output of the target blocks
+		/// up to that block.
+		fn reveal(revealed_at: BlockNumberFor<T>, output: [u8; 32]) -> Weight {
+			let mut ready = Vec::new();
+			AwaitingBlocks::<T>::mutate(|awaiting| {
+				ready.extend(
+					awaiting
+						.iter()
+						.copied()
+						.take_while(|block| *block <= revealed_at),
+				);
+				awaiting.retain(|block| *block > revealed_at);
+			});
+
+			for target in &ready {
+				Results::<T>::mutate_exists(target, |result| {
+					if let Some(pending) = result {
+						pending.randomness = Some(output);
+					}
+				});
+			}
+
+			let accesses = ready.len() as u64 + 1;
+			T::DbWeight::get().reads_writes(accesses, accesses)
+		}
+	}
+}
+
+/// Random word `index` of the request `id`.
+fn random_word(randomness: &[u8; 32], salt: &H256, id: RequestId, index: u8) -> H256 {
+	H256(sp_io::hashing::blake2_256(
+		&(randomness, salt, id, index).encode(),
+	))
+}
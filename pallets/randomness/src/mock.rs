@@ -0,0 +1,101 @@
+//! Test environment for the randomness pallet.
+
+use crate as pallet_randomness;
+use frame_support::{
+	derive_impl,
+	storage::{storage_prefix, unhashed},
+	traits::{ConstU32, ConstU64, ConstU8, Hooks},
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Babe: pallet_babe,
+		Balances: pallet_balances,
+		Randomness: pallet_randomness,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+impl pallet_babe::Config for Test {
+	type EpochDuration = ConstU64<10>;
+	type ExpectedBlockTime = ConstU64<2>;
+	type EpochChangeTrigger = pallet_babe::ExternalTrigger;
+	type DisabledValidators = ();
+	type WeightInfo = ();
+	type MaxAuthorities = ConstU32<10>;
+	type MaxNominators = ConstU32<0>;
+	type KeyOwnerProof = sp_core::Void;
+	type EquivocationReportSystem = ();
+}
+
+pub const DEPOSIT: u64 = 10;
+pub const EXPIRATION: u64 = 3;
+
+impl pallet_randomness::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Deposit = ConstU64<DEPOSIT>;
+	type MinBlockDelay = ConstU64<2>;
+	type MaxBlockDelay = ConstU64<5>;
+	type Expiration = ConstU64<EXPIRATION>;
+	type MaxRandomWords = ConstU8<4>;
+	type MaxTargetBlocks = ConstU32<3>;
+}
+
+/// Starts block `n`, whose parent revealed `output` if any.
+///
+/// BABE keeps the VRF output of the last block that had one, so passing `None` leaves the output
+/// of the previous block in place, as after a secondary slot.
+pub fn run_to_block(n: u64, output: Option<[u8; 32]>) {
+	if let Some(output) = output {
+		unhashed::put(
+			&storage_prefix(b"Babe", b"AuthorVrfRandomness"),
+			&Some(output),
+		);
+	}
+	System::set_block_number(n);
+	Randomness::on_initialize(n);
+}
+
+/// Starts epoch `index` of BABE, with `randomness`.
+pub fn start_epoch(index: u64, randomness: [u8; 32]) {
+	unhashed::put(&storage_prefix(b"Babe", b"EpochIndex"), &index);
+	unhashed::put(&storage_prefix(b"Babe", b"Randomness"), &randomness);
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
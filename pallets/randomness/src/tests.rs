@@ -0,0 +1,184 @@
+use crate::{
+	mock::*, random_word, AwaitingBlocks, Error, Event, RequestId, RequestStatus, Requests, Results,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+const SALT: H256 = H256::repeat_byte(7);
+
+fn request(requester: u64, num_words: u8, delay: u64) -> Result<RequestId, DispatchError> {
+	Randomness::request_randomness(requester, SALT, num_words, delay).map_err(Into::into)
+}
+
+fn fulfil(requester: u64, id: RequestId) -> Result<Vec<H256>, DispatchError> {
+	Randomness::fulfil_request(&requester, id).map_err(Into::into)
+}
+
+fn purge(purger: u64, id: RequestId) -> Result<(), DispatchError> {
+	Randomness::purge_expired_request(purger, id).map_err(Into::into)
+}
+
+#[test]
+fn requests_are_checked_and_reserve_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(request(1, 1, 1), Error::<Test>::InvalidDelay);
+		assert_noop!(request(1, 1, 6), Error::<Test>::InvalidDelay);
+		assert_noop!(request(1, 0, 2), Error::<Test>::InvalidNumberOfWords);
+		assert_noop!(request(1, 5, 2), Error::<Test>::InvalidNumberOfWords);
+		assert_noop!(request(3, 1, 2), Error::<Test>::CannotReserveDeposit);
+
+		assert_eq!(request(1, 1, 2), Ok(0));
+		System::assert_last_event(
+			Event::RandomnessRequested {
+				id: 0,
+				requester: 1,
+				target: 3,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+		assert_eq!(Randomness::request_status(0), RequestStatus::Pending);
+		assert_eq!(Randomness::request_status(1), RequestStatus::DoesNotExist);
+	});
+}
+
+#[test]
+fn target_blocks_are_bounded() {
+	new_test_ext().execute_with(|| {
+		for delay in 2..=4 {
+			assert_ok!(request(1, 1, delay));
+		}
+		// Requests on a target block already waiting do not count.
+		assert_ok!(request(2, 1, 3));
+		assert_eq!(AwaitingBlocks::<Test>::get().into_inner(), vec![3, 4, 5]);
+
+		assert_noop!(request(1, 1, 5), Error::<Test>::TooManyTargetBlocks);
+	});
+}
+
+#[test]
+fn requests_are_fulfilled_with_the_first_output_revealed_from_their_target() {
+	new_test_ext().execute_with(|| {
+		let id = request(1, 2, 2).unwrap();
+
+		// The output revealed at block 3 is that of block 2, before the target.
+		run_to_block(3, Some([1; 32]));
+		assert_eq!(Randomness::request_status(id), RequestStatus::Pending);
+		assert_noop!(fulfil(1, id), Error::<Test>::RandomnessNotReady);
+
+		run_to_block(4, Some([2; 32]));
+		assert_eq!(Randomness::request_status(id), RequestStatus::Ready);
+		assert!(AwaitingBlocks::<Test>::get().is_empty());
+		assert_noop!(fulfil(2, id), Error::<Test>::NotRequester);
+
+		assert_eq!(
+			fulfil(1, id),
+			Ok(vec![
+				random_word(&[2; 32], &SALT, id, 0),
+				random_word(&[2; 32], &SALT, id, 1),
+			])
+		);
+		System::assert_last_event(Event::RequestFulfilled { id }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Requests::<Test>::contains_key(id));
+		assert!(!Results::<Test>::contains_key(3));
+
+		// A request is fulfilled once.
+		assert_noop!(fulfil(1, id), Error::<Test>::RequestNotFound);
+	});
+}
+
+#[test]
+fn an_output_is_revealed_once() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2, Some([1; 32]));
+		let id = request(1, 1, 2).unwrap();
+
+		// Blocks 3 and 4 are secondary slots, so BABE still holds the output of block 1.
+		run_to_block(4, None);
+		run_to_block(5, None);
+		assert_eq!(Randomness::request_status(id), RequestStatus::Pending);
+
+		run_to_block(6, Some([3; 32]));
+		assert_eq!(fulfil(1, id), Ok(vec![random_word(&[3; 32], &SALT, id, 0)]));
+	});
+}
+
+#[test]
+fn requests_on_the_same_target_get_different_words() {
+	new_test_ext().execute_with(|| {
+		let first = request(1, 1, 2).unwrap();
+		let second = request(2, 1, 2).unwrap();
+		assert_eq!(Results::<Test>::get(3).unwrap().requests, 2);
+
+		run_to_block(4, Some([1; 32]));
+		let first_words = fulfil(1, first).unwrap();
+		// The result stays until the last request on the target is fulfilled.
+		assert_eq!(Results::<Test>::get(3).unwrap().requests, 1);
+		let second_words = fulfil(2, second).unwrap();
+		assert_ne!(first_words, second_words);
+		assert!(!Results::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn expired_requests_are_purged_by_anyone() {
+	new_test_ext().execute_with(|| {
+		let id = request(1, 1, 2).unwrap();
+		run_to_block(4, Some([1; 32]));
+
+		run_to_block(3 + EXPIRATION, None);
+		assert_eq!(Randomness::request_status(id), RequestStatus::Ready);
+		assert_noop!(purge(2, id), Error::<Test>::RequestNotExpired);
+
+		run_to_block(4 + EXPIRATION, None);
+		assert_eq!(Randomness::request_status(id), RequestStatus::Expired);
+		assert_noop!(fulfil(1, id), Error::<Test>::RequestExpired);
+
+		assert_ok!(purge(2, id));
+		System::assert_last_event(Event::RequestPurged { id, purger: 2 }.into());
+		assert_eq!(Balances::total_balance(&1), 100 - DEPOSIT);
+		assert_eq!(Balances::free_balance(2), 100 + DEPOSIT);
+		assert!(!Requests::<Test>::contains_key(id));
+		assert!(!Results::<Test>::contains_key(3));
+		assert_eq!(Randomness::request_status(id), RequestStatus::DoesNotExist);
+	});
+}
+
+#[test]
+fn requests_whose_output_is_never_revealed_expire() {
+	new_test_ext().execute_with(|| {
+		let id = request(1, 1, 2).unwrap();
+
+		run_to_block(4 + EXPIRATION, None);
+		assert_eq!(Randomness::request_status(id), RequestStatus::Expired);
+		assert_ok!(purge(1, id));
+		assert_eq!(Balances::free_balance(1), 100);
+
+		// A late output does not bring the request back.
+		run_to_block(5 + EXPIRATION, Some([1; 32]));
+		assert!(AwaitingBlocks::<Test>::get().is_empty());
+		assert!(!Results::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn epoch_randomness_follows_babe() {
+	new_test_ext().execute_with(|| {
+		start_epoch(1, [1; 32]);
+		run_to_block(2, None);
+		assert_eq!(Randomness::current_epoch_randomness(), [1; 32]);
+		assert_eq!(Randomness::previous_epoch_randomness(), [0; 32]);
+
+		// The randomness only changes with the epoch.
+		start_epoch(1, [9; 32]);
+		run_to_block(3, None);
+		assert_eq!(Randomness::current_epoch_randomness(), [1; 32]);
+
+		start_epoch(2, [2; 32]);
+		run_to_block(4, None);
+		assert_eq!(Randomness::current_epoch_randomness(), [2; 32]);
+		assert_eq!(Randomness::previous_epoch_randomness(), [1; 32]);
+	});
+}
@@ -0,0 +1,277 @@
+//! Precompile exposing the native balance of `pallet-balances` as an ERC-20 token.
+//!
+//! Balances and total supply are read straight from `pallet-balances`, transfers are dispatched
+//! as `transfer_allow_death` on behalf of the owner. Allowances have no pallet of their own and
+//! are kept in a dedicated storage map owned by this precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	storage::types::{StorageDoubleMap, ValueQuery},
+	traits::StorageInstance,
+	Blake2_128Concat,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::{Bounded, CheckedSub, Dispatchable, StaticLookup};
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+type BalanceOf<Runtime> = <Runtime as pallet_balances::Config>::Balance;
+type AccountIdOf<Runtime> = <Runtime as frame_system::Config>::AccountId;
+
+/// Metadata of the token, provided by the runtime.
+pub trait Erc20Metadata {
+	/// Returns the name of the token.
+	fn name() -> &'static str;
+
+	/// Returns the symbol of the token.
+	fn symbol() -> &'static str;
+
+	/// Returns the decimals places of the token.
+	fn decimals() -> u8;
+}
+
+/// Storage prefix of the allowances.
+pub struct ApprovesPrefix;
+
+impl StorageInstance for ApprovesPrefix {
+	const STORAGE_PREFIX: &'static str = "Approves";
+
+	fn pallet_prefix() -> &'static str {
+		"Erc20Balances"
+	}
+}
+
+/// Allowances, keyed by owner then spender.
+pub type ApprovesStorage<Runtime> = StorageDoubleMap<
+	ApprovesPrefix,
+	Blake2_128Concat,
+	AccountIdOf<Runtime>,
+	Blake2_128Concat,
+	AccountIdOf<Runtime>,
+	BalanceOf<Runtime>,
+	ValueQuery,
+>;
+
+/// ERC-20 view of the native token.
+pub struct Erc20BalancesPrecompile<Runtime, Metadata>(PhantomData<(Runtime, Metadata)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Metadata> Erc20BalancesPrecompile<Runtime, Metadata>
+where
+	Runtime: pallet_balances::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_balances::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	Metadata: Erc20Metadata,
+{
+	/// Transfers and approvals are made on behalf of the caller, so a contract must not be able
+	/// to run this precompile in its own context and spend the balance of the account that called
+	/// it.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	#[precompile::public("totalSupply()")]
+	#[precompile::view]
+	fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// TotalIssuance
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_balances::Pallet::<Runtime>::total_issuance().into())
+	}
+
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// Account
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+
+		Ok(pallet_balances::Pallet::<Runtime>::usable_balance(&owner).into())
+	}
+
+	#[precompile::public("allowance(address,address)")]
+	#[precompile::view]
+	fn allowance(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		spender: Address,
+	) -> EvmResult<U256> {
+		// Approves
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		let spender = Runtime::AddressMapping::into_account_id(spender.into());
+
+		Ok(ApprovesStorage::<Runtime>::get(owner, spender).into())
+	}
+
+	#[precompile::public("approve(address,uint256)")]
+	fn approve(handle: &mut impl PrecompileHandle, spender: Address, value: U256) -> EvmResult<bool> {
+		// Approves
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		let spender: H160 = spender.into();
+		let owner = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		// Approving more than the balance type can hold is an infinite approval.
+		let amount: BalanceOf<Runtime> = value.try_into().unwrap_or_else(|_| Bounded::max_value());
+		ApprovesStorage::<Runtime>::insert(
+			owner,
+			Runtime::AddressMapping::into_account_id(spender),
+			amount,
+		);
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_APPROVAL,
+			handle.context().caller,
+			spender,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transfer(address,uint256)")]
+	fn transfer(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let from = handle.context().caller;
+		let to: H160 = to.into();
+		Self::dispatch_transfer(handle, from, to, value)?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_TRANSFER,
+			from,
+			to,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transferFrom(address,address,uint256)")]
+	fn transfer_from(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		// Approves
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		let caller = handle.context().caller;
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+
+		// An owner moving its own funds does not need an allowance.
+		if caller != from {
+			let owner = Runtime::AddressMapping::into_account_id(from);
+			let spender = Runtime::AddressMapping::into_account_id(caller);
+			let allowance = ApprovesStorage::<Runtime>::get(&owner, &spender);
+
+			// An infinite approval is never decreased.
+			if allowance != BalanceOf::<Runtime>::max_value() {
+				// Approves
+				handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+				let amount = Self::u256_to_balance(value)?;
+				let allowance = allowance
+					.checked_sub(&amount)
+					.ok_or_else(|| revert("ERC20: insufficient allowance"))?;
+				ApprovesStorage::<Runtime>::insert(&owner, &spender, allowance);
+			}
+		}
+
+		Self::dispatch_transfer(handle, from, to, value)?;
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_TRANSFER,
+			from,
+			to,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	fn name(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+		Ok(Metadata::name().into())
+	}
+
+	#[precompile::public("symbol()")]
+	#[precompile::view]
+	fn symbol(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+		Ok(Metadata::symbol().into())
+	}
+
+	#[precompile::public("decimals()")]
+	#[precompile::view]
+	fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		Ok(Metadata::decimals())
+	}
+}
+
+impl<Runtime, Metadata> Erc20BalancesPrecompile<Runtime, Metadata>
+where
+	Runtime: pallet_balances::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_balances::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	Metadata: Erc20Metadata,
+{
+	fn dispatch_transfer(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		value: U256,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(from);
+		let dest = Runtime::AddressMapping::into_account_id(to);
+		let value = Self::u256_to_balance(value)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_balances::Call::<Runtime>::transfer_allow_death {
+				dest: <Runtime as frame_system::Config>::Lookup::unlookup(dest),
+				value,
+			},
+		)?;
+
+		Ok(())
+	}
+
+	fn u256_to_balance(value: U256) -> EvmResult<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("value").into())
+	}
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Batch contract's address.
+address constant BATCH_ADDRESS = 0x0000000000000000000000000000000000000802;
+
+/// @dev The Batch contract's instance.
+Batch constant BATCH_CONTRACT = Batch(BATCH_ADDRESS);
+
+/// @title Batch precompile
+/// @notice Make several calls in one transaction, each with the caller of the batch as
+/// `msg.sender`. `value`, `callData` and `gasLimit` may be shorter than `to`: a missing value
+/// or call data is empty, and a missing or zero gas limit forwards all the gas left.
+/// @custom:address 0x0000000000000000000000000000000000000802
+interface Batch {
+    /// @dev Make the calls, carrying on with the next one when a call fails.
+    /// @custom:selector 79df4b9c
+    function batchSome(
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory callData,
+        uint64[] memory gasLimit
+    ) external;
+
+    /// @dev Make the calls, stopping and succeeding at the first one that fails.
+    /// @custom:selector cf0491c7
+    function batchSomeUntilFailure(
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory callData,
+        uint64[] memory gasLimit
+    ) external;
+
+    /// @dev Make the calls, reverting the whole batch if one of them fails.
+    /// @custom:selector 96e292b8
+    function batchAll(
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory callData,
+        uint64[] memory gasLimit
+    ) external;
+
+    /// @dev Emitted when the call at `index` succeeded.
+    /// @custom:selector bf855484633929c3d6688eb3caf8eff910fb4bef030a8d7dbc9390d26759714d
+    event SubcallSucceeded(uint256 index);
+
+    /// @dev Emitted when the call at `index` reverted, failed or could not be made.
+    /// @custom:selector dbc5d06f4f877f959b1ff12d2161cdd693fa8e442ee53f1790b2804b24881f05
+    event SubcallFailed(uint256 index);
+}
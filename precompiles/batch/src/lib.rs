@@ -0,0 +1,249 @@
+//! Precompile to batch several calls in one transaction, each made on behalf of the caller.
+//!
+//! Every subcall is made with the caller of the batch as `msg.sender`, so an externally owned
+//! account can, for example, approve a token and spend the allowance in the same transaction.
+//! A `SubcallSucceeded(uint256)` or `SubcallFailed(uint256)` log with the index of the subcall
+//! is emitted after each of them. The three modes only differ in how a failed subcall is handled:
+//!
+//! - `batchSome` carries on with the next subcall,
+//! - `batchSomeUntilFailure` stops there and succeeds,
+//! - `batchAll` reverts the whole batch.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::{iter::repeat, marker::PhantomData};
+
+use fp_evm::{Context, ExitError, ExitReason, Log, PrecompileFailure, PrecompileHandle, Transfer};
+use precompile_utils::{evm::costs::call_cost, prelude::*};
+use sp_core::{ConstU32, H160, U256};
+use sp_std::vec::Vec;
+
+/// Solidity selector of the SubcallSucceeded log, which is the Keccak of the Log signature.
+pub const LOG_SUBCALL_SUCCEEDED: [u8; 32] = keccak256!("SubcallSucceeded(uint256)");
+
+/// Solidity selector of the SubcallFailed log, which is the Keccak of the Log signature.
+pub const LOG_SUBCALL_FAILED: [u8; 32] = keccak256!("SubcallFailed(uint256)");
+
+/// Maximum number of subcalls in a batch.
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+
+/// Maximum size of the call data of a subcall.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+/// Log emitted when the subcall at `index` succeeded.
+pub fn log_subcall_succeeded(address: impl Into<H160>, index: usize) -> Log {
+	log1(
+		address,
+		LOG_SUBCALL_SUCCEEDED,
+		solidity::encode_event_data(U256::from(index)),
+	)
+}
+
+/// Log emitted when the subcall at `index` reverted, failed or could not be made.
+pub fn log_subcall_failed(address: impl Into<H160>, index: usize) -> Log {
+	log1(
+		address,
+		LOG_SUBCALL_FAILED,
+		solidity::encode_event_data(U256::from(index)),
+	)
+}
+
+/// How a batch handles a failed subcall.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+	/// Carries on with the next subcall.
+	BatchSome,
+	/// Stops and succeeds.
+	BatchSomeUntilFailure,
+	/// Reverts the whole batch.
+	BatchAll,
+}
+
+/// Batches calls of the same caller.
+pub struct BatchPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> BatchPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	/// Subcalls are made on behalf of the caller, so a contract must not be able to run the
+	/// batch in its own context and act as the account that called it.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	#[precompile::public("batchSome(address[],uint256[],bytes[],uint64[])")]
+	fn batch_some(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::batch(handle, Mode::BatchSome, to, value, call_data, gas_limit)
+	}
+
+	#[precompile::public("batchSomeUntilFailure(address[],uint256[],bytes[],uint64[])")]
+	fn batch_some_until_failure(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::batch(
+			handle,
+			Mode::BatchSomeUntilFailure,
+			to,
+			value,
+			call_data,
+			gas_limit,
+		)
+	}
+
+	#[precompile::public("batchAll(address[],uint256[],bytes[],uint64[])")]
+	fn batch_all(
+		handle: &mut impl PrecompileHandle,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		Self::batch(handle, Mode::BatchAll, to, value, call_data, gas_limit)
+	}
+}
+
+impl<Runtime> BatchPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	/// Makes one subcall per address in `to`. `value`, `call_data` and `gas_limit` may be
+	/// shorter than `to`: a missing value or call data is empty, and a missing or zero gas limit
+	/// forwards all the gas left.
+	fn batch(
+		handle: &mut impl PrecompileHandle,
+		mode: Mode,
+		to: BoundedVec<Address, GetArrayLimit>,
+		value: BoundedVec<U256, GetArrayLimit>,
+		call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
+		gas_limit: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult {
+		let values = Vec::from(value).into_iter().map(Some).chain(repeat(None));
+		let calls_data = Vec::from(call_data)
+			.into_iter()
+			.map(Some)
+			.chain(repeat(None));
+		let gas_limits = Vec::from(gas_limit)
+			.into_iter()
+			.map(|limit| (limit != 0).then_some(limit))
+			.chain(repeat(None));
+
+		// Every subcall ends with a log of the same size, whatever the index.
+		let log_cost = log_subcall_failed(handle.code_address(), 0)
+			.compute_cost()
+			.map_err(|_| revert("Failed to compute log cost"))?;
+
+		let subcalls = Vec::from(to)
+			.into_iter()
+			.zip(values)
+			.zip(calls_data)
+			.zip(gas_limits);
+		for (index, (((Address(address), value), call_data), gas_limit)) in subcalls.enumerate() {
+			let value = value.unwrap_or_default();
+			let call_data = call_data.map(Into::into).unwrap_or_default();
+
+			// Keep enough gas to emit the log of the subcall.
+			let Some(forwarded_gas) = handle.remaining_gas().checked_sub(log_cost) else {
+				return match mode {
+					Mode::BatchAll => Err(out_of_gas()),
+					_ => Ok(()),
+				};
+			};
+
+			// The batch pays for the call itself, as the CALL opcode would.
+			let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
+			let forwarded_gas = match (forwarded_gas.checked_sub(call_cost), gas_limit) {
+				(Some(available), None) => Some(available),
+				(Some(available), Some(limit)) if limit <= available => Some(limit),
+				_ => None,
+			};
+			let Some(forwarded_gas) = forwarded_gas else {
+				Self::record_log(handle, log_subcall_failed(handle.code_address(), index))?;
+				match mode {
+					Mode::BatchAll => return Err(out_of_gas()),
+					Mode::BatchSomeUntilFailure => return Ok(()),
+					Mode::BatchSome => continue,
+				}
+			};
+
+			handle.record_cost(call_cost)?;
+
+			let caller = handle.context().caller;
+			let context = Context {
+				caller,
+				address,
+				apparent_value: value,
+			};
+			let transfer = (!value.is_zero()).then_some(Transfer {
+				source: caller,
+				target: address,
+				value,
+			});
+
+			let (reason, output) = handle.call(
+				address,
+				transfer,
+				call_data,
+				Some(forwarded_gas),
+				false,
+				&context,
+			);
+
+			let log = match reason {
+				ExitReason::Succeed(_) => log_subcall_succeeded(handle.code_address(), index),
+				_ => log_subcall_failed(handle.code_address(), index),
+			};
+			Self::record_log(handle, log)?;
+
+			match (mode, reason) {
+				(_, ExitReason::Fatal(exit_status)) => {
+					return Err(PrecompileFailure::Fatal { exit_status })
+				}
+				(Mode::BatchAll, ExitReason::Revert(exit_status)) => {
+					return Err(PrecompileFailure::Revert {
+						exit_status,
+						output,
+					})
+				}
+				(Mode::BatchAll, ExitReason::Error(exit_status)) => {
+					return Err(PrecompileFailure::Error { exit_status })
+				}
+				(Mode::BatchSomeUntilFailure, ExitReason::Revert(_) | ExitReason::Error(_)) => {
+					return Ok(())
+				}
+				_ => (),
+			}
+		}
+
+		Ok(())
+	}
+
+	fn record_log(handle: &mut impl PrecompileHandle, log: Log) -> EvmResult {
+		handle.record_log_costs(&[&log])?;
+		log.record(handle)
+	}
+}
+
+fn out_of_gas() -> PrecompileFailure {
+	PrecompileFailure::Error {
+		exit_status: ExitError::OutOfGas,
+	}
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The CallPermit contract's address.
+address constant CALL_PERMIT_ADDRESS = 0x0000000000000000000000000000000000000803;
+
+/// @dev The CallPermit contract's instance.
+CallPermit constant CALL_PERMIT_CONTRACT = CallPermit(CALL_PERMIT_ADDRESS);
+
+/// @title Call permit precompile
+/// @notice Dispatch a call authorized by an EIP-712 permit of its sender, who does not need to
+/// pay for the transaction. The permit is signed over
+/// `CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit,uint256 nonce,uint256 deadline)`
+/// in the domain `EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)`
+/// named "Call Permit Precompile", version "1".
+/// @custom:address 0x0000000000000000000000000000000000000803
+interface CallPermit {
+    /// @dev Make the call of the permit with `from` as `msg.sender`.
+    /// @param deadline Timestamp in seconds after which the permit expires.
+    /// @return output The output of the call.
+    /// @custom:selector b5ea0966
+    function dispatch(
+        address from,
+        address to,
+        uint256 value,
+        bytes memory data,
+        uint64 gaslimit,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external returns (bytes memory output);
+
+    /// @dev The nonce that the next permit of `owner` must be signed with.
+    /// @custom:selector 7ecebe00
+    function nonces(address owner) external view returns (uint256);
+
+    /// @dev The EIP-712 domain separator of the permits.
+    /// @custom:selector 3644e515
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+}
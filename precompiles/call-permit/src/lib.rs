@@ -0,0 +1,213 @@
+//! Precompile to dispatch an EVM call authorized by an EIP-712 permit of its sender.
+//!
+//! A relayer submits the permit and pays for the transaction, while the call is made with the
+//! signer of the permit as `msg.sender`. A permit is bound to this precompile and to the chain
+//! id of `pallet-evm`, and it can only be used once, before its deadline: every signer has a
+//! nonce, kept in a storage map owned by this precompile, that each dispatched permit bumps.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::{Context, ExitReason, PrecompileFailure, PrecompileHandle, Transfer};
+use frame_support::{
+	storage::types::{StorageMap, ValueQuery},
+	traits::{Get, StorageInstance, Time},
+	Blake2_128Concat,
+};
+use precompile_utils::{evm::costs::call_cost, prelude::*};
+use sp_core::{ConstU32, H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::vec::Vec;
+
+/// EIP-712 type hash of a permit.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-712 type hash of the domain.
+pub const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Maximum size of the call data of a permit.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+/// Storage prefix of the nonces.
+pub struct NoncesPrefix;
+
+impl StorageInstance for NoncesPrefix {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"CallPermit"
+	}
+}
+
+/// Nonce of the next permit of each signer.
+pub type NoncesStorage = StorageMap<NoncesPrefix, Blake2_128Concat, H160, U256, ValueQuery>;
+
+/// Dispatches calls authorized by permits.
+pub struct CallPermitPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> CallPermitPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	#[precompile::public(
+		"dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<GetCallDataLimit>,
+		gas_limit: u64,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<UnboundedBytes> {
+		// Timestamp, ChainId, Nonces
+		handle.record_cost(
+			3_000
+				+ 2 * RuntimeHelper::<Runtime>::db_read_gas_cost()
+				+ RuntimeHelper::<Runtime>::db_write_gas_cost(),
+		)?;
+
+		let (from, to): (H160, H160) = (from.into(), to.into());
+		let data: Vec<u8> = data.into();
+
+		// The relayer must forward at least the gas the signer asked for.
+		let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
+		let total_cost = gas_limit
+			.checked_add(call_cost)
+			.ok_or_else(|| revert("Call require too much gas (uint64 overflow)"))?;
+		if total_cost > handle.remaining_gas() {
+			return Err(revert("Gaslimit is too low to dispatch provided call"));
+		}
+
+		// The block timestamp is in milliseconds, permit deadlines in seconds.
+		let now: u128 = <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+		if deadline < U256::from(now / 1000) {
+			return Err(revert("Permit expired"));
+		}
+
+		let nonce = NoncesStorage::get(from);
+		let permit = Self::generate_permit(
+			handle.code_address(),
+			from,
+			to,
+			value,
+			&data,
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		let mut signature = [0u8; 65];
+		signature[..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &permit)
+			.map_err(|_| revert("Invalid permit"))?;
+		if H160::from_slice(&keccak_256(&signer)[12..]) != from {
+			return Err(revert("Invalid permit"));
+		}
+
+		NoncesStorage::insert(from, nonce + 1);
+
+		handle.record_cost(call_cost)?;
+
+		let context = Context {
+			caller: from,
+			address: to,
+			apparent_value: value,
+		};
+		let transfer = (!value.is_zero()).then_some(Transfer {
+			source: from,
+			target: to,
+			value,
+		});
+		let (reason, output) = handle.call(to, transfer, data, Some(gas_limit), false, &context);
+
+		match reason {
+			ExitReason::Succeed(_) => Ok(output.into()),
+			ExitReason::Revert(_) => Err(revert(output)),
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+		}
+	}
+
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// Nonces
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(NoncesStorage::get(H160::from(owner)))
+	}
+
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// ChainId
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(Self::compute_domain_separator(handle.code_address()).into())
+	}
+}
+
+impl<Runtime> CallPermitPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config,
+{
+	fn compute_domain_separator(address: H160) -> [u8; 32] {
+		let name = H256::from(keccak_256(b"Call Permit Precompile"));
+		let version = H256::from(keccak256!("1"));
+		let chain_id = U256::from(<Runtime as pallet_evm::Config>::ChainId::get());
+
+		keccak_256(&solidity::encode_arguments((
+			H256::from(PERMIT_DOMAIN),
+			name,
+			version,
+			chain_id,
+			Address(address),
+		)))
+	}
+
+	/// EIP-712 digest that `from` signs to let the precompile at `address` make the call.
+	#[allow(clippy::too_many_arguments)]
+	pub fn generate_permit(
+		address: H160,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: &[u8],
+		gas_limit: u64,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let permit = keccak_256(&solidity::encode_arguments((
+			H256::from(PERMIT_TYPEHASH),
+			Address(from),
+			Address(to),
+			value,
+			H256::from(keccak_256(data)),
+			gas_limit,
+			nonce,
+			deadline,
+		)));
+
+		let mut digest = Vec::with_capacity(2 + 32 + 32);
+		digest.extend_from_slice(b"\x19\x01");
+		digest.extend_from_slice(&Self::compute_domain_separator(address));
+		digest.extend_from_slice(&permit);
+		keccak_256(&digest)
+	}
+}
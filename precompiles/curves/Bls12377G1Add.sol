@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12377G1Add contract's address.
+address constant BLS12377_G1_ADD_ADDRESS = 0x0000000000000000000000000000000000000402;
+
+/// @title BLS12-377 G1 addition
+/// @notice Takes raw input instead of ABI encoded calls. The input is two 128 byte G1 points, each
+/// coordinate a base field element padded to 64 bytes. The output is their sum.
+/// @custom:address 0x0000000000000000000000000000000000000402
+interface Bls12377G1Add {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12377G1Msm contract's address.
+address constant BLS12377_G1_MSM_ADDRESS = 0x0000000000000000000000000000000000000403;
+
+/// @title BLS12-377 G1 multi-scalar multiplication
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 128 byte G1 point
+/// and a 32 byte scalar. The output is the sum of their products.
+/// @custom:address 0x0000000000000000000000000000000000000403
+interface Bls12377G1Msm {}
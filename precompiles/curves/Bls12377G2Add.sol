@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12377G2Add contract's address.
+address constant BLS12377_G2_ADD_ADDRESS = 0x0000000000000000000000000000000000000404;
+
+/// @title BLS12-377 G2 addition
+/// @notice Takes raw input instead of ABI encoded calls. The input is two 256 byte G2 points, each
+/// coordinate two base field elements padded to 64 bytes. The output is their sum.
+/// @custom:address 0x0000000000000000000000000000000000000404
+interface Bls12377G2Add {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12377G2Msm contract's address.
+address constant BLS12377_G2_MSM_ADDRESS = 0x0000000000000000000000000000000000000405;
+
+/// @title BLS12-377 G2 multi-scalar multiplication
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 256 byte G2 point
+/// and a 32 byte scalar. The output is the sum of their products.
+/// @custom:address 0x0000000000000000000000000000000000000405
+interface Bls12377G2Msm {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381G1Add contract's address.
+address constant BLS12381_G1_ADD_ADDRESS = 0x000000000000000000000000000000000000000b;
+
+/// @title BLS12-381 G1 addition, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is two 128 byte G1 points, each
+/// coordinate a base field element padded to 64 bytes. The output is their sum.
+/// @custom:address 0x000000000000000000000000000000000000000b
+interface Bls12381G1Add {}
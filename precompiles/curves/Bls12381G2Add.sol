@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381G2Add contract's address.
+address constant BLS12381_G2_ADD_ADDRESS = 0x000000000000000000000000000000000000000d;
+
+/// @title BLS12-381 G2 addition, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is two 256 byte G2 points, each
+/// coordinate two base field elements padded to 64 bytes. The output is their sum.
+/// @custom:address 0x000000000000000000000000000000000000000d
+interface Bls12381G2Add {}
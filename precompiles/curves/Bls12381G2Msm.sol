@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381G2Msm contract's address.
+address constant BLS12381_G2_MSM_ADDRESS = 0x000000000000000000000000000000000000000E;
+
+/// @title BLS12-381 G2 multi-scalar multiplication, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 256 byte G2 point
+/// and a 32 byte scalar. The output is the sum of their products.
+/// @custom:address 0x000000000000000000000000000000000000000E
+interface Bls12381G2Msm {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381MapG1 contract's address.
+address constant BLS12381_MAP_G1_ADDRESS = 0x0000000000000000000000000000000000000010;
+
+/// @title BLS12-381 map to G1, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is a base field element padded
+/// to 64 bytes. The output is the 128 byte G1 point it maps to.
+/// @custom:address 0x0000000000000000000000000000000000000010
+interface Bls12381MapG1 {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381MapG2 contract's address.
+address constant BLS12381_MAP_G2_ADDRESS = 0x0000000000000000000000000000000000000011;
+
+/// @title BLS12-381 map to G2, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is a quadratic extension field
+/// element, two base field elements padded to 64 bytes. The output is the 256 byte G2 point it maps
+/// to.
+/// @custom:address 0x0000000000000000000000000000000000000011
+interface Bls12381MapG2 {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bls12381Pairing contract's address.
+address constant BLS12381_PAIRING_ADDRESS = 0x000000000000000000000000000000000000000F;
+
+/// @title BLS12-381 pairing check, as specified by EIP-2537
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 128 byte G1 point
+/// and a 256 byte G2 point. The output is a 32 byte word holding 1 if the product of their pairings
+/// is the identity and 0 otherwise.
+/// @custom:address 0x000000000000000000000000000000000000000F
+interface Bls12381Pairing {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bw6761G1Add contract's address.
+address constant BW6761_G1_ADD_ADDRESS = 0x0000000000000000000000000000000000000407;
+
+/// @title BW6-761 G1 addition
+/// @notice Takes raw input instead of ABI encoded calls. The input is two 192 byte G1 points, each
+/// coordinate a 96 byte base field element. The output is their sum.
+/// @custom:address 0x0000000000000000000000000000000000000407
+interface Bw6761G1Add {}
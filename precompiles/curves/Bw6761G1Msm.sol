@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bw6761G1Msm contract's address.
+address constant BW6761_G1_MSM_ADDRESS = 0x0000000000000000000000000000000000000408;
+
+/// @title BW6-761 G1 multi-scalar multiplication
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 192 byte G1 point
+/// and a scalar padded to 64 bytes. The output is the sum of their products.
+/// @custom:address 0x0000000000000000000000000000000000000408
+interface Bw6761G1Msm {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bw6761G2Msm contract's address.
+address constant BW6761_G2_MSM_ADDRESS = 0x000000000000000000000000000000000000040A;
+
+/// @title BW6-761 G2 multi-scalar multiplication
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 192 byte G2 point
+/// and a scalar padded to 64 bytes. The output is the sum of their products.
+/// @custom:address 0x000000000000000000000000000000000000040A
+interface Bw6761G2Msm {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bw6761Pairing contract's address.
+address constant BW6761_PAIRING_ADDRESS = 0x000000000000000000000000000000000000040B;
+
+/// @title BW6-761 pairing check
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 192 byte G1 point
+/// and a 192 byte G2 point. The output is a 32 byte word holding 1 if the product of their pairings
+/// is the identity and 0 otherwise.
+/// @custom:address 0x000000000000000000000000000000000000040B
+interface Bw6761Pairing {}
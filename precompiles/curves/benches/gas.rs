@@ -0,0 +1,379 @@
+//! Derives the gas costs of the curves without an EIP from timings of the BLS12-381 precompiles,
+//! and checks the costs in the code against them.
+//!
+//! Run with `cargo bench -p pallet-evm-precompile-curves`. Every precompile runs on random valid
+//! inputs of the sizes its schedule distinguishes. The gas per microsecond that EIP-2537 charges
+//! for a BLS12-381 operation is the rate at which the same operation of BLS12-377 and BW6-761 is
+//! charged, at the same number of pairs. The bench prints the costs that this gives, and fails if
+//! a cost in the code charges less than `TOLERANCE` of that rate.
+//!
+//! The curves run each input in turn rather than one after the other, so that changes in the
+//! speed of the machine, frequent on shared ones, affect them all the same.
+
+extern crate alloc;
+
+use std::time::{Duration, Instant};
+
+use ark_ec::{
+	short_weierstrass::{Projective, SWCurveConfig},
+	CurveGroup,
+};
+use ark_ff::UniformRand;
+use ark_std::{rand::Rng, test_rng};
+use fp_evm::{Context, Precompile};
+use pallet_evm_precompile_curves::*;
+use pallet_evm_test_vector_support::MockHandle;
+use sp_core::{H160, U256};
+
+#[allow(dead_code)]
+#[path = "../src/ops.rs"]
+mod ops;
+
+use ops::FieldCodec;
+
+/// Curves in the order of the samples, BLS12-381 first.
+const CURVES: [&str; 3] = ["BLS12-381", "BLS12-377", "BW6-761"];
+
+/// Numbers of pairs of the multi-scalar multiplications.
+const MSM_SIZES: [usize; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+
+/// Numbers of pairs of the pairing checks.
+const PAIRING_SIZES: [usize; 4] = [1, 2, 4, 8];
+
+/// Share of the BLS12-381 rate under which a cost fails the bench, leaving room for timing noise.
+const TOLERANCE: f64 = 0.9;
+
+/// Gas charged for an input, median time to run it, and median ratio of that time to the time
+/// of the first input run in the same round.
+#[derive(Clone, Copy)]
+struct Sample {
+	gas: u64,
+	micros: f64,
+	ratio: f64,
+}
+
+impl Sample {
+	fn rate(&self) -> f64 {
+		self.gas as f64 / self.micros
+	}
+
+	/// Gas that running this input costs at the rate charged for `reference`, the first input.
+	fn reference_gas(&self, reference: &Sample) -> f64 {
+		reference.gas as f64 * self.ratio
+	}
+}
+
+/// Runs a precompile on an input and returns the gas it charged.
+type Runner = fn(&[u8]) -> u64;
+
+fn runner<P: Precompile>(input: &[u8]) -> u64 {
+	let context = Context {
+		address: H160::zero(),
+		caller: H160::zero(),
+		apparent_value: U256::zero(),
+	};
+	let mut handle = MockHandle::new(input.to_vec(), None, context);
+	P::execute(&mut handle).expect("input is valid");
+	handle.gas_used
+}
+
+/// Runs each precompile on its input in turn, at least ten times and for at least 500ms.
+///
+/// The ratios to the first input are taken within each round, so that a change in the speed of the
+/// machine between rounds, or while only the shortest input runs, does not skew them.
+fn run(cases: &[(Runner, Vec<u8>)]) -> Vec<Sample> {
+	let mut gas = vec![0; cases.len()];
+	let mut micros = vec![Vec::new(); cases.len()];
+	let mut ratios = vec![Vec::new(); cases.len()];
+	let start = Instant::now();
+	while micros[0].len() < 10 || start.elapsed() < Duration::from_millis(500) {
+		for (index, (runner, input)) in cases.iter().enumerate() {
+			let run = Instant::now();
+			gas[index] = runner(input);
+			micros[index].push(run.elapsed().as_secs_f64() * 1e6);
+		}
+		let first = *micros[0].last().expect("just pushed");
+		for (ratios, micros) in ratios.iter_mut().zip(&micros) {
+			ratios.push(micros.last().expect("just pushed") / first);
+		}
+	}
+
+	(0..cases.len())
+		.map(|index| Sample {
+			gas: gas[index],
+			micros: median(&mut micros[index]),
+			ratio: median(&mut ratios[index]),
+		})
+		.collect()
+}
+
+fn median(values: &mut [f64]) -> f64 {
+	values.sort_by(f64::total_cmp);
+	values[values.len() / 2]
+}
+
+/// Samples of an operation for each curve, in the order of `CURVES`, and each number of pairs.
+fn bench(
+	sizes: &[usize],
+	mut cases: impl FnMut(usize) -> [(Runner, Vec<u8>); 3],
+) -> [Vec<Sample>; 3] {
+	let mut samples: [Vec<Sample>; 3] = Default::default();
+	for pairs in sizes {
+		for (curve, sample) in samples.iter_mut().zip(run(&cases(*pairs))) {
+			curve.push(sample);
+		}
+	}
+	samples
+}
+
+fn point<P: SWCurveConfig>(rng: &mut impl Rng) -> Vec<u8>
+where
+	P::BaseField: FieldCodec,
+{
+	ops::encode_point::<P>(Projective::<P>::rand(rng).into_affine())
+}
+
+fn field_element<F: FieldCodec + UniformRand>(rng: &mut impl Rng) -> Vec<u8> {
+	let mut out = vec![0u8; F::LENGTH];
+	F::rand(rng).encode(&mut out);
+	out
+}
+
+fn add_input<P: SWCurveConfig>(rng: &mut impl Rng) -> Vec<u8>
+where
+	P::BaseField: FieldCodec,
+{
+	[point::<P>(rng), point::<P>(rng)].concat()
+}
+
+fn msm_input<P: SWCurveConfig>(rng: &mut impl Rng, pairs: usize) -> Vec<u8>
+where
+	P::BaseField: FieldCodec,
+	P::ScalarField: FieldCodec,
+{
+	(0..pairs)
+		.flat_map(|_| [point::<P>(rng), field_element::<P::ScalarField>(rng)].concat())
+		.collect()
+}
+
+fn pairing_input<P1: SWCurveConfig, P2: SWCurveConfig>(rng: &mut impl Rng, pairs: usize) -> Vec<u8>
+where
+	P1::BaseField: FieldCodec,
+	P2::BaseField: FieldCodec,
+{
+	(0..pairs)
+		.flat_map(|_| [point::<P1>(rng), point::<P2>(rng)].concat())
+		.collect()
+}
+
+/// Prints the samples of an operation, and returns those charged under `TOLERANCE` of the
+/// BLS12-381 rate.
+fn check(operation: &str, sizes: &[usize], samples: &[Vec<Sample>; 3]) -> Vec<String> {
+	let mut undercharged = Vec::new();
+	for (index, pairs) in sizes.iter().enumerate() {
+		let reference = samples[0][index];
+		for (curve, samples) in CURVES.iter().zip(samples) {
+			let name = format!("{curve} {operation} ({pairs})");
+			let sample = samples[index];
+			let share = sample.gas as f64 / sample.reference_gas(&reference);
+			println!(
+				"{name:<28} {:>9} gas {:>10.1} us {:>9.0} gas at the BLS12-381 rate {:>5.0}%",
+				sample.gas,
+				sample.micros,
+				sample.reference_gas(&reference),
+				share * 100.0,
+			);
+			if share < TOLERANCE {
+				undercharged.push(name);
+			}
+		}
+	}
+	undercharged
+}
+
+fn round_up(gas: f64, step: u64) -> u64 {
+	(gas / step as f64).ceil() as u64 * step
+}
+
+/// Cost of an addition charged at the reference rate, rounded up to 10 gas.
+fn derive_add(sample: &Sample, reference: &Sample) -> u64 {
+	round_up(sample.reference_gas(reference), 10)
+}
+
+/// Multiplication cost of a multi-scalar multiplication charged at the reference rate for every
+/// number of pairs, with the same discounts, rounded up to 100 gas.
+fn derive_msm(samples: &[Sample], reference: &[Sample]) -> u64 {
+	// Single pairs are not discounted, so they are charged the multiplication cost.
+	let multiplication_cost = samples[0].gas as f64;
+	let scale = samples
+		.iter()
+		.zip(reference)
+		.map(|(sample, reference)| sample.reference_gas(reference) / sample.gas as f64)
+		.fold(0.0, f64::max);
+
+	round_up(multiplication_cost * scale, 100)
+}
+
+/// Base and per pair costs of a pairing check charged at the reference rate for every number of
+/// pairs: the per pair cost is the slope of the least squares line, and the base cost covers
+/// every sample, both rounded up to 100 gas.
+fn derive_pairing(samples: &[Sample], reference: &[Sample]) -> (u64, u64) {
+	let points: Vec<(f64, f64)> = PAIRING_SIZES
+		.iter()
+		.zip(samples.iter().zip(reference))
+		.map(|(pairs, (sample, reference))| (*pairs as f64, sample.reference_gas(reference)))
+		.collect();
+	let n = points.len() as f64;
+	let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+	let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+	let slope = points
+		.iter()
+		.map(|(x, y)| (x - mean_x) * (y - mean_y))
+		.sum::<f64>()
+		/ points
+			.iter()
+			.map(|(x, _)| (x - mean_x).powi(2))
+			.sum::<f64>();
+
+	let per_pair = round_up(slope, 100);
+	let base = points
+		.iter()
+		.map(|(x, y)| y - x * per_pair as f64)
+		.fold(0.0, f64::max);
+	(round_up(base, 100), per_pair)
+}
+
+fn main() {
+	use ark_bls12_377::{g1 as bls12_377_g1, g2 as bls12_377_g2};
+	use ark_bls12_381::{g1 as bls12_381_g1, g2 as bls12_381_g2};
+	use ark_bw6_761::{g1 as bw6_761_g1, g2 as bw6_761_g2};
+
+	let rng = &mut test_rng();
+
+	let g1_add = bench(&[2], |_| {
+		[
+			(
+				runner::<Bls12381G1Add>,
+				add_input::<bls12_381_g1::Config>(rng),
+			),
+			(
+				runner::<Bls12377G1Add>,
+				add_input::<bls12_377_g1::Config>(rng),
+			),
+			(runner::<Bw6761G1Add>, add_input::<bw6_761_g1::Config>(rng)),
+		]
+	});
+	let g1_msm = bench(&MSM_SIZES, |pairs| {
+		[
+			(
+				runner::<Bls12381G1Msm>,
+				msm_input::<bls12_381_g1::Config>(rng, pairs),
+			),
+			(
+				runner::<Bls12377G1Msm>,
+				msm_input::<bls12_377_g1::Config>(rng, pairs),
+			),
+			(
+				runner::<Bw6761G1Msm>,
+				msm_input::<bw6_761_g1::Config>(rng, pairs),
+			),
+		]
+	});
+	let g2_add = bench(&[2], |_| {
+		[
+			(
+				runner::<Bls12381G2Add>,
+				add_input::<bls12_381_g2::Config>(rng),
+			),
+			(
+				runner::<Bls12377G2Add>,
+				add_input::<bls12_377_g2::Config>(rng),
+			),
+			(runner::<Bw6761G2Add>, add_input::<bw6_761_g2::Config>(rng)),
+		]
+	});
+	let g2_msm = bench(&MSM_SIZES, |pairs| {
+		[
+			(
+				runner::<Bls12381G2Msm>,
+				msm_input::<bls12_381_g2::Config>(rng, pairs),
+			),
+			(
+				runner::<Bls12377G2Msm>,
+				msm_input::<bls12_377_g2::Config>(rng, pairs),
+			),
+			(
+				runner::<Bw6761G2Msm>,
+				msm_input::<bw6_761_g2::Config>(rng, pairs),
+			),
+		]
+	});
+	let pairing = bench(&PAIRING_SIZES, |pairs| {
+		[
+			(
+				runner::<Bls12381Pairing>,
+				pairing_input::<bls12_381_g1::Config, bls12_381_g2::Config>(rng, pairs),
+			),
+			(
+				runner::<Bls12377Pairing>,
+				pairing_input::<bls12_377_g1::Config, bls12_377_g2::Config>(rng, pairs),
+			),
+			(
+				runner::<Bw6761Pairing>,
+				pairing_input::<bw6_761_g1::Config, bw6_761_g2::Config>(rng, pairs),
+			),
+		]
+	});
+
+	let undercharged = [
+		check("G1Add", &[2], &g1_add),
+		check("G1Msm", &MSM_SIZES, &g1_msm),
+		check("G2Add", &[2], &g2_add),
+		check("G2Msm", &MSM_SIZES, &g2_msm),
+		check("Pairing", &PAIRING_SIZES, &pairing),
+	]
+	.concat();
+
+	// The maps to curve only exist for BLS12-381.
+	let map = run(&[
+		(
+			runner::<Bls12381MapG1>,
+			field_element::<ark_bls12_381::Fq>(rng),
+		),
+		(
+			runner::<Bls12381MapG2>,
+			field_element::<ark_bls12_381::Fq2>(rng),
+		),
+	]);
+	for (operation, sample) in ["MapG1", "MapG2"].iter().zip(map) {
+		let name = format!("BLS12-381 {operation} (1)");
+		println!(
+			"{name:<28} {:>9} gas {:>10.1} us {:>6.1} gas/us",
+			sample.gas,
+			sample.micros,
+			sample.rate(),
+		);
+	}
+
+	for (index, curve) in CURVES.iter().enumerate().skip(1) {
+		let (base, per_pair) = derive_pairing(&pairing[index], &pairing[0]);
+		println!("{curve} costs at the BLS12-381 rates:");
+		println!(
+			"  G1Add {}, G1Msm multiplication {}",
+			derive_add(&g1_add[index][0], &g1_add[0][0]),
+			derive_msm(&g1_msm[index], &g1_msm[0]),
+		);
+		println!(
+			"  G2Add {}, G2Msm multiplication {}",
+			derive_add(&g2_add[index][0], &g2_add[0][0]),
+			derive_msm(&g2_msm[index], &g2_msm[0]),
+		);
+		println!("  Pairing base {base}, per pair {per_pair}");
+	}
+
+	assert!(
+		undercharged.is_empty(),
+		"charged under {TOLERANCE} of the BLS12-381 rate: {}",
+		undercharged.join(", "),
+	);
+}
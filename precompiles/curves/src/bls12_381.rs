@@ -0,0 +1,129 @@
+//! BLS12-381 precompiles, as specified by EIP-2537.
+
+use ark_bls12_381::{g1, g2, Bls12_381};
+use fp_evm::{Precompile, PrecompileHandle, PrecompileResult};
+
+use crate::{execute, msm_cost, ops};
+
+/// Per-mille discount of a G1 multi-scalar multiplication, indexed by number of pairs minus one.
+pub(crate) const G1_MSM_DISCOUNTS: [u64; 128] = [
+	1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665,
+	661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615,
+	613, 611, 609, 608, 606, 604, 603, 601, 599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585,
+	584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563,
+	562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545,
+	544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529,
+	528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// Per-mille discount of a G2 multi-scalar multiplication, indexed by number of pairs minus one.
+pub(crate) const G2_MSM_DISCOUNTS: [u64; 128] = [
+	1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704, 699,
+	693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632, 629,
+	627, 624, 622, 620, 618, 615, 613, 611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593, 592,
+	590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568, 567,
+	566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547,
+	546, 545, 544, 543, 542, 541, 541, 540, 539, 538, 538, 537, 536, 536, 535, 534, 534, 533, 532,
+	532, 531, 530, 530, 529, 529, 528, 527, 527, 526, 526, 525, 524, 524,
+];
+
+/// Addition of two G1 points.
+pub struct Bls12381G1Add;
+
+impl Bls12381G1Add {
+	const GAS_COST: u64 = 375;
+}
+
+impl Precompile for Bls12381G1Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		execute(handle, Self::GAS_COST, ops::add::<g1::Config>)
+	}
+}
+
+/// Multi-scalar multiplication of G1 points.
+pub struct Bls12381G1Msm;
+
+impl Bls12381G1Msm {
+	const MULTIPLICATION_COST: u64 = 12_000;
+}
+
+impl Precompile for Bls12381G1Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = ops::msm_pairs::<g1::Config>(handle.input().len());
+		let gas = msm_cost(pairs, Self::MULTIPLICATION_COST, &G1_MSM_DISCOUNTS);
+
+		execute(handle, gas, ops::msm::<g1::Config>)
+	}
+}
+
+/// Addition of two G2 points.
+pub struct Bls12381G2Add;
+
+impl Bls12381G2Add {
+	const GAS_COST: u64 = 600;
+}
+
+impl Precompile for Bls12381G2Add {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		execute(handle, Self::GAS_COST, ops::add::<g2::Config>)
+	}
+}
+
+/// Multi-scalar multiplication of G2 points.
+pub struct Bls12381G2Msm;
+
+impl Bls12381G2Msm {
+	const MULTIPLICATION_COST: u64 = 22_500;
+}
+
+impl Precompile for Bls12381G2Msm {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = ops::msm_pairs::<g2::Config>(handle.input().len());
+		let gas = msm_cost(pairs, Self::MULTIPLICATION_COST, &G2_MSM_DISCOUNTS);
+
+		execute(handle, gas, ops::msm::<g2::Config>)
+	}
+}
+
+/// Pairing check over pairs of G1 and G2 points.
+pub struct Bls12381Pairing;
+
+impl Bls12381Pairing {
+	const BASE_GAS_COST: u64 = 37_700;
+	const PER_PAIR_GAS_COST: u64 = 32_600;
+}
+
+impl Precompile for Bls12381Pairing {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let pairs = ops::pairing_pairs::<g1::Config, g2::Config>(handle.input().len()) as u64;
+		let gas = Self::PER_PAIR_GAS_COST.saturating_mul(pairs).saturating_add(Self::BASE_GAS_COST);
+
+		execute(handle, gas, ops::pairing_check::<Bls12_381, g1::Config, g2::Config>)
+	}
+}
+
+/// Maps a base field element to a G1 point.
+pub struct Bls12381MapG1;
+
+impl Bls12381MapG1 {
+	const GAS_COST: u64 = 5_500;
+}
+
+impl Precompile for Bls12381MapG1 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		execute(handle, Self::GAS_COST, ops::map_to_curve::<g1::Config>)
+	}
+}
+
+/// Maps a quadratic extension field element to a G2 point.
+pub struct Bls12381MapG2;
+
+impl Bls12381MapG2 {
+	const GAS_COST: u64 = 23_800;
+}
+
+impl Precompile for Bls12381MapG2 {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		execute(handle, Self::GAS_COST, ops::map_to_curve::<g2::Config>)
+	}
+}
@@ -0,0 +1,132 @@
+//! Elliptic curve precompiles backed by arkworks.
+//!
+//! Every curve shares the byte encoding described in [`ops`], so a precompile is a gas schedule
+//! and a call into the generic arithmetic for its curve.
+//!
+//! BLS12-381 is priced by EIP-2537. The other curves have no EIP: each of their operations is
+//! benchmarked against the BLS12-381 operation it mirrors and priced at the same gas per second,
+//! reusing the EIP-2537 multi-scalar multiplication discounts. `benches/gas.rs` derives those
+//! costs from the BLS12-381 timings, and fails if a cost charges less than it derives.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod bls12_377;
+mod bls12_381;
+mod bw6_761;
+mod ops;
+
+pub use bls12_377::{Bls12377G1Add, Bls12377G1Msm, Bls12377G2Add, Bls12377G2Msm, Bls12377Pairing};
+pub use bls12_381::{
+	Bls12381G1Add, Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapG1, Bls12381MapG2,
+	Bls12381Pairing,
+};
+pub use bw6_761::{Bw6761G1Add, Bw6761G1Msm, Bw6761G2Add, Bw6761G2Msm, Bw6761Pairing};
+
+use alloc::vec::Vec;
+
+use fp_evm::{
+	ExitError, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+
+/// Charges `gas`, then runs `op` on the call input.
+fn execute(
+	handle: &mut impl PrecompileHandle,
+	gas: u64,
+	op: impl FnOnce(&[u8]) -> Result<Vec<u8>, ops::Error>,
+) -> PrecompileResult {
+	handle.record_cost(gas)?;
+
+	let output = op(handle.input()).map_err(|error| PrecompileFailure::Error {
+		exit_status: ExitError::Other(error.into()),
+	})?;
+
+	Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output })
+}
+
+/// Cost of a multi-scalar multiplication of `pairs` points: `pairs` single multiplications,
+/// discounted by the per-mille factor for that many pairs. The last discount applies to any
+/// larger input.
+fn msm_cost(pairs: usize, multiplication_cost: u64, discounts: &[u64]) -> u64 {
+	let discount = discounts[pairs.clamp(1, discounts.len()) - 1];
+
+	(pairs as u64).saturating_mul(multiplication_cost).saturating_mul(discount) / 1000
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::{
+		test_precompile_failure_test_vectors, test_precompile_test_vectors,
+	};
+
+	#[test]
+	fn bls12_381_matches_eip2537_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Bls12381G1Add>("../testdata/blsG1Add.json")?;
+		test_precompile_failure_test_vectors::<Bls12381G1Add>("../testdata/fail-blsG1Add.json")?;
+		test_precompile_test_vectors::<Bls12381G1Msm>("../testdata/blsG1MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bls12381G1Msm>(
+			"../testdata/fail-blsG1MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bls12381G2Add>("../testdata/blsG2Add.json")?;
+		test_precompile_failure_test_vectors::<Bls12381G2Add>("../testdata/fail-blsG2Add.json")?;
+		test_precompile_test_vectors::<Bls12381G2Msm>("../testdata/blsG2MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bls12381G2Msm>(
+			"../testdata/fail-blsG2MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bls12381Pairing>("../testdata/blsPairing.json")?;
+		test_precompile_failure_test_vectors::<Bls12381Pairing>(
+			"../testdata/fail-blsPairing.json",
+		)?;
+		test_precompile_test_vectors::<Bls12381MapG1>("../testdata/blsMapG1.json")?;
+		test_precompile_failure_test_vectors::<Bls12381MapG1>("../testdata/fail-blsMapG1.json")?;
+		test_precompile_test_vectors::<Bls12381MapG2>("../testdata/blsMapG2.json")?;
+		test_precompile_failure_test_vectors::<Bls12381MapG2>("../testdata/fail-blsMapG2.json")
+	}
+
+	// `testdata/crosscheck_bls12377_bw6761.py` recomputes these vectors without arkworks.
+	#[test]
+	fn bls12_377_matches_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Bls12377G1Add>("../testdata/bls12377G1Add.json")?;
+		test_precompile_failure_test_vectors::<Bls12377G1Add>(
+			"../testdata/fail-bls12377G1Add.json",
+		)?;
+		test_precompile_test_vectors::<Bls12377G1Msm>("../testdata/bls12377G1MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bls12377G1Msm>(
+			"../testdata/fail-bls12377G1MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bls12377G2Add>("../testdata/bls12377G2Add.json")?;
+		test_precompile_failure_test_vectors::<Bls12377G2Add>(
+			"../testdata/fail-bls12377G2Add.json",
+		)?;
+		test_precompile_test_vectors::<Bls12377G2Msm>("../testdata/bls12377G2MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bls12377G2Msm>(
+			"../testdata/fail-bls12377G2MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bls12377Pairing>("../testdata/bls12377Pairing.json")?;
+		test_precompile_failure_test_vectors::<Bls12377Pairing>(
+			"../testdata/fail-bls12377Pairing.json",
+		)
+	}
+
+	// `testdata/crosscheck_bls12377_bw6761.py` recomputes these vectors without arkworks.
+	#[test]
+	fn bw6_761_matches_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Bw6761G1Add>("../testdata/bw6761G1Add.json")?;
+		test_precompile_failure_test_vectors::<Bw6761G1Add>("../testdata/fail-bw6761G1Add.json")?;
+		test_precompile_test_vectors::<Bw6761G1Msm>("../testdata/bw6761G1MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bw6761G1Msm>(
+			"../testdata/fail-bw6761G1MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bw6761G2Add>("../testdata/bw6761G2Add.json")?;
+		test_precompile_failure_test_vectors::<Bw6761G2Add>("../testdata/fail-bw6761G2Add.json")?;
+		test_precompile_test_vectors::<Bw6761G2Msm>("../testdata/bw6761G2MultiExp.json")?;
+		test_precompile_failure_test_vectors::<Bw6761G2Msm>(
+			"../testdata/fail-bw6761G2MultiExp.json",
+		)?;
+		test_precompile_test_vectors::<Bw6761Pairing>("../testdata/bw6761Pairing.json")?;
+		test_precompile_failure_test_vectors::<Bw6761Pairing>("../testdata/fail-bw6761Pairing.json")
+	}
+}
@@ -0,0 +1,232 @@
+//! Curve arithmetic on the byte encoding shared by all curve precompiles.
+//!
+//! Field elements are big-endian and left-padded with zeros to a multiple of 32 bytes, extension
+//! field elements are the concatenation of their coefficients, and points are the concatenation
+//! of their affine coordinates with the point at infinity encoded as all zeros. Scalars are
+//! big-endian, padded the same way, and reduced modulo the group order.
+
+use alloc::{vec, vec::Vec};
+
+use ark_ec::{
+	hashing::{
+		curve_maps::wb::{WBConfig, WBMap},
+		map_to_curve_hasher::MapToCurve,
+	},
+	pairing::Pairing,
+	short_weierstrass::{Affine, Projective, SWCurveConfig},
+	AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_ff::{
+	fields::{Fp, Fp2, Fp2Config, FpConfig},
+	BigInteger, PrimeField, Zero,
+};
+
+pub type Error = &'static str;
+
+/// Bytes needed to hold `bits`, rounded up to whole 32 byte words.
+const fn padded_length(bits: u32) -> usize {
+	(bits as usize).div_ceil(8).div_ceil(32) * 32
+}
+
+/// A field element with a fixed length encoding.
+pub trait FieldCodec: Sized {
+	/// Length of the encoded element, padding included.
+	const LENGTH: usize;
+
+	/// Decodes an element, rejecting non-zero padding and non-canonical values.
+	fn decode(input: &[u8]) -> Result<Self, Error>;
+
+	/// Encodes the element into `out`, which must be `LENGTH` bytes long and zeroed.
+	fn encode(&self, out: &mut [u8]);
+}
+
+impl<P: FpConfig<N>, const N: usize> FieldCodec for Fp<P, N> {
+	const LENGTH: usize = padded_length(<Self as PrimeField>::MODULUS_BIT_SIZE);
+
+	fn decode(input: &[u8]) -> Result<Self, Error> {
+		let bytes = N * 8;
+		let (padding, value) = input.split_at(Self::LENGTH - bytes);
+		if padding.iter().any(|byte| *byte != 0) {
+			return Err("invalid field element top bytes");
+		}
+		let element = Self::from_be_bytes_mod_order(value);
+		if element.into_bigint().to_bytes_be() != value {
+			return Err("field element is not canonical");
+		}
+		Ok(element)
+	}
+
+	fn encode(&self, out: &mut [u8]) {
+		let bytes = self.into_bigint().to_bytes_be();
+		out[Self::LENGTH - bytes.len()..].copy_from_slice(&bytes);
+	}
+}
+
+impl<P: Fp2Config> FieldCodec for Fp2<P>
+where
+	P::Fp: FieldCodec,
+{
+	const LENGTH: usize = 2 * P::Fp::LENGTH;
+
+	fn decode(input: &[u8]) -> Result<Self, Error> {
+		let (c0, c1) = input.split_at(P::Fp::LENGTH);
+		Ok(Self::new(P::Fp::decode(c0)?, P::Fp::decode(c1)?))
+	}
+
+	fn encode(&self, out: &mut [u8]) {
+		let (c0, c1) = out.split_at_mut(P::Fp::LENGTH);
+		self.c0.encode(c0);
+		self.c1.encode(c1);
+	}
+}
+
+/// Length of an encoded point of the curve `P`.
+pub const fn point_length<P: SWCurveConfig>() -> usize
+where
+	P::BaseField: FieldCodec,
+{
+	2 * P::BaseField::LENGTH
+}
+
+/// Length of an encoded scalar of the curve `P`.
+pub const fn scalar_length<P: SWCurveConfig>() -> usize {
+	padded_length(<P::ScalarField as PrimeField>::MODULUS_BIT_SIZE)
+}
+
+/// Decodes a point, checking that it is on the curve and, optionally, in the prime order
+/// subgroup.
+pub fn decode_point<P: SWCurveConfig>(
+	input: &[u8],
+	subgroup_check: bool,
+) -> Result<Affine<P>, Error>
+where
+	P::BaseField: FieldCodec,
+{
+	let (x, y) = input.split_at(P::BaseField::LENGTH);
+	let (x, y) = (P::BaseField::decode(x)?, P::BaseField::decode(y)?);
+	if x.is_zero() && y.is_zero() {
+		return Ok(Affine::identity());
+	}
+
+	let point = Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err("point is not on curve");
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err("point is not in the correct subgroup");
+	}
+	Ok(point)
+}
+
+pub fn encode_point<P: SWCurveConfig>(point: Affine<P>) -> Vec<u8>
+where
+	P::BaseField: FieldCodec,
+{
+	let mut out = vec![0u8; point_length::<P>()];
+	if let Some((x, y)) = point.xy() {
+		let (x_out, y_out) = out.split_at_mut(P::BaseField::LENGTH);
+		x.encode(x_out);
+		y.encode(y_out);
+	}
+	out
+}
+
+/// Adds two points. No subgroup check is performed.
+pub fn add<P: SWCurveConfig>(input: &[u8]) -> Result<Vec<u8>, Error>
+where
+	P::BaseField: FieldCodec,
+{
+	if input.len() != 2 * point_length::<P>() {
+		return Err("invalid input length");
+	}
+	let (a, b) = input.split_at(point_length::<P>());
+	let (a, b) = (decode_point::<P>(a, false)?, decode_point::<P>(b, false)?);
+
+	Ok(encode_point::<P>((a + b).into_affine()))
+}
+
+/// Number of `(point, scalar)` pairs in a multi-scalar multiplication input.
+pub const fn msm_pairs<P: SWCurveConfig>(input_length: usize) -> usize
+where
+	P::BaseField: FieldCodec,
+{
+	input_length / (point_length::<P>() + scalar_length::<P>())
+}
+
+/// Multi-scalar multiplication of points in the prime order subgroup.
+pub fn msm<P: SWCurveConfig>(input: &[u8]) -> Result<Vec<u8>, Error>
+where
+	P::BaseField: FieldCodec,
+{
+	let pair_length = point_length::<P>() + scalar_length::<P>();
+	if input.is_empty() || input.len() % pair_length != 0 {
+		return Err("invalid input length");
+	}
+
+	let mut bases = Vec::with_capacity(input.len() / pair_length);
+	let mut scalars = Vec::with_capacity(input.len() / pair_length);
+	for pair in input.chunks_exact(pair_length) {
+		let (point, scalar) = pair.split_at(point_length::<P>());
+		bases.push(decode_point::<P>(point, true)?);
+		scalars.push(P::ScalarField::from_be_bytes_mod_order(scalar));
+	}
+
+	Ok(encode_point::<P>(Projective::<P>::msm_unchecked(&bases, &scalars).into_affine()))
+}
+
+/// Number of `(G1, G2)` pairs in a pairing check input.
+pub const fn pairing_pairs<P1: SWCurveConfig, P2: SWCurveConfig>(input_length: usize) -> usize
+where
+	P1::BaseField: FieldCodec,
+	P2::BaseField: FieldCodec,
+{
+	input_length / (point_length::<P1>() + point_length::<P2>())
+}
+
+/// Checks that the product of the pairings of all pairs is the identity. Returns a 32 byte word
+/// holding 1 if it is and 0 otherwise.
+pub fn pairing_check<E, P1, P2>(input: &[u8]) -> Result<Vec<u8>, Error>
+where
+	E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+	P1: SWCurveConfig,
+	P2: SWCurveConfig,
+	P1::BaseField: FieldCodec,
+	P2::BaseField: FieldCodec,
+{
+	let pair_length = point_length::<P1>() + point_length::<P2>();
+	if input.is_empty() || input.len() % pair_length != 0 {
+		return Err("invalid input length");
+	}
+
+	let mut g1 = Vec::with_capacity(input.len() / pair_length);
+	let mut g2 = Vec::with_capacity(input.len() / pair_length);
+	for pair in input.chunks_exact(pair_length) {
+		let (a, b) = pair.split_at(point_length::<P1>());
+		g1.push(decode_point::<P1>(a, true)?);
+		g2.push(decode_point::<P2>(b, true)?);
+	}
+
+	let mut out = vec![0u8; 32];
+	if E::multi_pairing(g1, g2).is_zero() {
+		out[31] = 1;
+	}
+	Ok(out)
+}
+
+/// Maps a field element to a point in the prime order subgroup, using the simplified SWU map
+/// to an isogenous curve followed by cofactor clearing.
+pub fn map_to_curve<P: WBConfig>(input: &[u8]) -> Result<Vec<u8>, Error>
+where
+	P::BaseField: FieldCodec,
+{
+	if input.len() != P::BaseField::LENGTH {
+		return Err("invalid input length");
+	}
+	let element = P::BaseField::decode(input)?;
+
+	let point = WBMap::<P>::new()
+		.and_then(|map| map.map_to_curve(element))
+		.map_err(|_| "map to curve failed")?;
+
+	Ok(encode_point::<P>(point.clear_cofactor()))
+}
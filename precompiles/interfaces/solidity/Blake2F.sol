@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Blake2F contract's address.
+address constant BLAKE2F_ADDRESS = 0x0000000000000000000000000000000000000009;
+
+/// @title BLAKE2b compression function F, as specified by EIP-152
+/// @notice Takes raw input instead of ABI encoded calls. The input is the number of rounds as a 4
+/// byte big endian integer, the 64 byte state `h`, the 128 byte message block `m`, the 16 byte
+/// offset counter `t` and a final block flag byte. The output is the new 64 byte state.
+/// @custom:address 0x0000000000000000000000000000000000000009
+interface Blake2F {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bn128Add contract's address.
+address constant BN128_ADD_ADDRESS = 0x0000000000000000000000000000000000000006;
+
+/// @title alt_bn128 addition, as specified by EIP-196
+/// @notice Takes raw input instead of ABI encoded calls. The input is two G1 points, each two 32
+/// byte coordinates. The output is their sum.
+/// @custom:address 0x0000000000000000000000000000000000000006
+interface Bn128Add {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bn128Mul contract's address.
+address constant BN128_MUL_ADDRESS = 0x0000000000000000000000000000000000000007;
+
+/// @title alt_bn128 scalar multiplication, as specified by EIP-196
+/// @notice Takes raw input instead of ABI encoded calls. The input is a G1 point, two 32 byte
+/// coordinates, and a 32 byte scalar. The output is their product.
+/// @custom:address 0x0000000000000000000000000000000000000007
+interface Bn128Mul {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Bn128Pairing contract's address.
+address constant BN128_PAIRING_ADDRESS = 0x0000000000000000000000000000000000000008;
+
+/// @title alt_bn128 pairing check, as specified by EIP-197
+/// @notice Takes raw input instead of ABI encoded calls. The input is pairs of a 64 byte G1 point
+/// and a 128 byte G2 point. The output is a 32 byte word holding 1 if the product of their pairings
+/// is the identity and 0 otherwise.
+/// @custom:address 0x0000000000000000000000000000000000000008
+interface Bn128Pairing {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Dispatch contract's address.
+address constant DISPATCH_ADDRESS = 0x0000000000000000000000000000000000000805;
+
+/// @title Substrate call dispatch
+/// @notice Takes raw input instead of ABI encoded calls. The input is a SCALE encoded runtime call,
+/// which is dispatched with the caller as signed origin. Only the calls that the runtime allows can
+/// be dispatched, any other one fails.
+/// @custom:address 0x0000000000000000000000000000000000000805
+interface Dispatch {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ECRecover contract's address.
+address constant ECRECOVER_ADDRESS = 0x0000000000000000000000000000000000000001;
+
+/// @title ECDSA public key recovery
+/// @notice Takes raw input instead of ABI encoded calls. The input is the message hash, `v`, `r`
+/// and `s` as 32 byte words. The output is the address of the signer, left-padded to 32 bytes, or
+/// no data if the signature is invalid.
+/// @custom:address 0x0000000000000000000000000000000000000001
+interface ECRecover {}
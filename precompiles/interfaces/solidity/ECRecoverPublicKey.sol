@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ECRecoverPublicKey contract's address.
+address constant ECRECOVER_PUBLIC_KEY_ADDRESS = 0x0000000000000000000000000000000000000401;
+
+/// @title ECDSA public key recovery
+/// @notice Takes raw input instead of ABI encoded calls. The input is the message hash, `v`, `r`
+/// and `s` as 32 byte words, as for ecrecover. The output is the 64 byte uncompressed public key of
+/// the signer, without its prefix.
+/// @custom:address 0x0000000000000000000000000000000000000401
+interface ECRecoverPublicKey {}
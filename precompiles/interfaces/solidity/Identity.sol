@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Identity contract's address.
+address constant IDENTITY_ADDRESS = 0x0000000000000000000000000000000000000004;
+
+/// @title Identity
+/// @notice Takes raw input instead of ABI encoded calls. The output is the input.
+/// @custom:address 0x0000000000000000000000000000000000000004
+interface Identity {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Modexp contract's address.
+address constant MODEXP_ADDRESS = 0x0000000000000000000000000000000000000005;
+
+/// @title Modular exponentiation, as specified by EIP-198
+/// @notice Takes raw input instead of ABI encoded calls. The input is the lengths of the base, the
+/// exponent and the modulus as 32 byte words, followed by them. The output is `base ** exponent %
+/// modulus`, as long as the modulus.
+/// @custom:address 0x0000000000000000000000000000000000000005
+interface Modexp {}
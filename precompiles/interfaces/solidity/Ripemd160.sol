@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Ripemd160 contract's address.
+address constant RIPEMD160_ADDRESS = 0x0000000000000000000000000000000000000003;
+
+/// @title RIPEMD-160
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 20 byte digest, left-padded to 32 bytes.
+/// @custom:address 0x0000000000000000000000000000000000000003
+interface Ripemd160 {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Sha256 contract's address.
+address constant SHA256_ADDRESS = 0x0000000000000000000000000000000000000002;
+
+/// @title SHA-256
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 32 byte digest.
+/// @custom:address 0x0000000000000000000000000000000000000002
+interface Sha256 {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Sha3FIPS256 contract's address.
+address constant SHA3_FIPS256_ADDRESS = 0x0000000000000000000000000000000000000400;
+
+/// @title SHA3-256, as specified by FIPS 202
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 32 byte digest.
+/// @custom:address 0x0000000000000000000000000000000000000400
+interface Sha3FIPS256 {}
@@ -0,0 +1,715 @@
+//! Solidity interfaces of the precompiles, and the JSON ABIs generated from them.
+//!
+//! Every precompile declares a Solidity interface in a `.sol` file next to its code, tagged with
+//! its address in a `@custom:address` NatSpec comment and with the selector of every function
+//! and event in a `@custom:selector` one. The interface of a precompile that takes raw input
+//! instead of ABI encoded calls has no functions and documents the input and output instead.
+//! The precompiles of Frontier have their interfaces in this crate.
+//!
+//! The parser only understands what these files use: one interface per file, whose members are
+//! functions and events with elementary and array types.
+
+use std::fmt;
+
+use serde_json::{json, Value};
+use sp_core::{hashing::keccak_256, H160};
+
+/// Sources of the Solidity interfaces of all the precompiles.
+pub const SOURCES: &[&str] = &[
+	// Frontier
+	include_str!("../solidity/ECRecover.sol"),
+	include_str!("../solidity/Sha256.sol"),
+	include_str!("../solidity/Ripemd160.sol"),
+	include_str!("../solidity/Identity.sol"),
+	include_str!("../solidity/Modexp.sol"),
+	include_str!("../solidity/Bn128Add.sol"),
+	include_str!("../solidity/Bn128Mul.sol"),
+	include_str!("../solidity/Bn128Pairing.sol"),
+	include_str!("../solidity/Blake2F.sol"),
+	include_str!("../solidity/Sha3FIPS256.sol"),
+	include_str!("../solidity/ECRecoverPublicKey.sol"),
+	include_str!("../solidity/Dispatch.sol"),
+	// Impetus
+	include_str!("../../kzg/KzgPointEvaluation.sol"),
+	include_str!("../../curves/Bls12381G1Add.sol"),
+	include_str!("../../curves/Bls12381G1Msm.sol"),
+	include_str!("../../curves/Bls12381G2Add.sol"),
+	include_str!("../../curves/Bls12381G2Msm.sol"),
+	include_str!("../../curves/Bls12381Pairing.sol"),
+	include_str!("../../curves/Bls12381MapG1.sol"),
+	include_str!("../../curves/Bls12381MapG2.sol"),
+	include_str!("../../p256/P256Verify.sol"),
+	include_str!("../../curves/Bls12377G1Add.sol"),
+	include_str!("../../curves/Bls12377G1Msm.sol"),
+	include_str!("../../curves/Bls12377G2Add.sol"),
+	include_str!("../../curves/Bls12377G2Msm.sol"),
+	include_str!("../../curves/Bls12377Pairing.sol"),
+	include_str!("../../curves/Bw6761G1Add.sol"),
+	include_str!("../../curves/Bw6761G1Msm.sol"),
+	include_str!("../../curves/Bw6761G2Add.sol"),
+	include_str!("../../curves/Bw6761G2Msm.sol"),
+	include_str!("../../curves/Bw6761Pairing.sol"),
+	include_str!("../../substrate-crypto/Sr25519Verify.sol"),
+	include_str!("../../substrate-crypto/Ed25519Verify.sol"),
+	include_str!("../../substrate-crypto/Blake2b128.sol"),
+	include_str!("../../substrate-crypto/Blake2b256.sol"),
+	include_str!("../../substrate-crypto/Twox64.sol"),
+	include_str!("../../substrate-crypto/Twox128.sol"),
+	include_str!("../../poseidon/Poseidon.sol"),
+	include_str!("../../staking/StakingInterface.sol"),
+	include_str!("../../balances-erc20/ERC20.sol"),
+	include_str!("../../batch/Batch.sol"),
+	include_str!("../../call-permit/CallPermit.sol"),
+	include_str!("../../precompile-registry/PrecompileRegistry.sol"),
+	include_str!("../../randomness/Randomness.sol"),
+	include_str!("../../nomination-pools/NominationPools.sol"),
+];
+
+/// Parses the interfaces of all the precompiles.
+pub fn interfaces() -> Result<Vec<Interface>, Error> {
+	SOURCES.iter().copied().map(Interface::parse).collect()
+}
+
+/// Error in the source of an interface.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl std::error::Error for Error {}
+
+fn error<T>(message: impl Into<String>) -> Result<T, Error> {
+	Err(Error(message.into()))
+}
+
+/// Solidity interface of a precompile.
+#[derive(Clone, Debug)]
+pub struct Interface {
+	pub name: String,
+	pub address: H160,
+	pub functions: Vec<Function>,
+	pub events: Vec<Event>,
+	/// The Solidity source the interface was parsed from.
+	pub source: &'static str,
+}
+
+/// Function of an interface.
+#[derive(Clone, Debug)]
+pub struct Function {
+	pub name: String,
+	pub inputs: Vec<Param>,
+	pub outputs: Vec<Param>,
+	pub mutability: Mutability,
+	/// Selector given by the `@custom:selector` comment.
+	pub documented_selector: Option<u32>,
+}
+
+/// Event of an interface.
+#[derive(Clone, Debug)]
+pub struct Event {
+	pub name: String,
+	pub inputs: Vec<Param>,
+	/// Topic given by the `@custom:selector` comment.
+	pub documented_selector: Option<[u8; 32]>,
+}
+
+/// Parameter of a function or an event, or value returned by a function.
+#[derive(Clone, Debug)]
+pub struct Param {
+	/// Name of the parameter, empty if it has none.
+	pub name: String,
+	/// Canonical ABI type.
+	pub ty: String,
+	pub indexed: bool,
+}
+
+/// State mutability of a function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mutability {
+	Pure,
+	View,
+	NonPayable,
+	Payable,
+}
+
+impl Mutability {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::Pure => "pure",
+			Self::View => "view",
+			Self::NonPayable => "nonpayable",
+			Self::Payable => "payable",
+		}
+	}
+}
+
+fn signature(name: &str, inputs: &[Param]) -> String {
+	let types: Vec<&str> = inputs.iter().map(|param| param.ty.as_str()).collect();
+	format!("{}({})", name, types.join(","))
+}
+
+impl Function {
+	/// Canonical signature, such as `transfer(address,uint256)`.
+	pub fn signature(&self) -> String {
+		signature(&self.name, &self.inputs)
+	}
+
+	/// The first four bytes of the Keccak of the signature.
+	pub fn selector(&self) -> u32 {
+		let hash = keccak_256(self.signature().as_bytes());
+		u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+	}
+}
+
+impl Event {
+	/// Canonical signature, such as `Transfer(address,address,uint256)`.
+	pub fn signature(&self) -> String {
+		signature(&self.name, &self.inputs)
+	}
+
+	/// The Keccak of the signature, the first topic of the logs of the event.
+	pub fn selector(&self) -> [u8; 32] {
+		keccak_256(self.signature().as_bytes())
+	}
+}
+
+fn abi_params(params: &[Param], with_indexed: bool) -> Value {
+	params
+		.iter()
+		.map(|param| {
+			let mut abi = json!({
+				"internalType": param.ty,
+				"name": param.name,
+				"type": param.ty,
+			});
+			if with_indexed {
+				abi["indexed"] = param.indexed.into();
+			}
+			abi
+		})
+		.collect()
+}
+
+impl Interface {
+	/// Parses the only interface of a Solidity source.
+	pub fn parse(source: &'static str) -> Result<Self, Error> {
+		let tokens = tokenize(source)?;
+		let mut parser = Parser {
+			tokens: &tokens,
+			position: 0,
+		};
+
+		let mut interface = None;
+		let mut docs = Vec::new();
+		while let Some(token) = parser.next() {
+			match token {
+				Token::Doc(line) => docs.push(line.clone()),
+				Token::Word(word) if word == "interface" => {
+					if interface.is_some() {
+						return error("More than one interface in the source");
+					}
+					interface = Some(parser.interface(source, &docs)?);
+					docs.clear();
+				}
+				_ => docs.clear(),
+			}
+		}
+
+		interface.ok_or_else(|| Error("No interface in the source".to_string()))
+	}
+
+	/// JSON ABI of the interface, as the Solidity compiler outputs it.
+	pub fn abi(&self) -> Value {
+		let functions = self.functions.iter().map(|function| {
+			json!({
+				"inputs": abi_params(&function.inputs, false),
+				"name": function.name,
+				"outputs": abi_params(&function.outputs, false),
+				"stateMutability": function.mutability.as_str(),
+				"type": "function",
+			})
+		});
+		let events = self.events.iter().map(|event| {
+			json!({
+				"anonymous": false,
+				"inputs": abi_params(&event.inputs, true),
+				"name": event.name,
+				"type": "event",
+			})
+		});
+		functions.chain(events).collect()
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+	/// Content of a `///` comment line.
+	Doc(String),
+	/// Identifier, keyword or number.
+	Word(String),
+	Punct(char),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, Error> {
+	let mut tokens = Vec::new();
+	let mut rest = source;
+	while let Some(c) = rest.chars().next() {
+		if c.is_whitespace() {
+			rest = &rest[c.len_utf8()..];
+		} else if let Some(comment) = rest.strip_prefix("//") {
+			let end = comment.find('\n').unwrap_or(comment.len());
+			if let Some(doc) = comment[..end].strip_prefix('/') {
+				tokens.push(Token::Doc(doc.trim().to_string()));
+			}
+			rest = &comment[end..];
+		} else if let Some(comment) = rest.strip_prefix("/*") {
+			let Some(end) = comment.find("*/") else {
+				return error("Unterminated comment");
+			};
+			rest = &comment[end + 2..];
+		} else if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+			let end = rest
+				.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+				.unwrap_or(rest.len());
+			tokens.push(Token::Word(rest[..end].to_string()));
+			rest = &rest[end..];
+		} else {
+			tokens.push(Token::Punct(c));
+			rest = &rest[c.len_utf8()..];
+		}
+	}
+	Ok(tokens)
+}
+
+/// Value of the NatSpec `tag` in `docs`.
+fn doc_tag<'a>(docs: &'a [String], tag: &str) -> Option<&'a str> {
+	docs.iter()
+		.find_map(|line| line.strip_prefix(tag))
+		.map(str::trim)
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, Error> {
+	hex::decode(value.trim_start_matches("0x"))
+		.or_else(|_| error(format!("Invalid hexadecimal value {value}")))
+}
+
+/// Canonical ABI name of an elementary type, or `None` if it is not one.
+fn elementary_type(ty: &str) -> Option<String> {
+	let size = |prefix: &str| ty.strip_prefix(prefix)?.parse::<usize>().ok();
+	let valid = match ty {
+		"address" | "bool" | "string" | "bytes" => true,
+		"uint" => return Some("uint256".to_string()),
+		"int" => return Some("int256".to_string()),
+		_ => {
+			matches!(size("bytes"), Some(1..=32))
+				|| [size("uint"), size("int")]
+					.into_iter()
+					.flatten()
+					.any(|bits| bits % 8 == 0 && (8..=256).contains(&bits))
+		}
+	};
+	valid.then(|| ty.to_string())
+}
+
+struct Parser<'a> {
+	tokens: &'a [Token],
+	position: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn next(&mut self) -> Option<&'a Token> {
+		let token = self.tokens.get(self.position);
+		self.position += 1;
+		token
+	}
+
+	fn peek(&self) -> Option<&'a Token> {
+		self.tokens.get(self.position)
+	}
+
+	fn word(&mut self) -> Result<&'a str, Error> {
+		match self.next() {
+			Some(Token::Word(word)) => Ok(word),
+			token => error(format!("Expected a word, found {token:?}")),
+		}
+	}
+
+	fn punct(&mut self, expected: char) -> Result<(), Error> {
+		match self.next() {
+			Some(Token::Punct(c)) if *c == expected => Ok(()),
+			token => error(format!("Expected `{expected}`, found {token:?}")),
+		}
+	}
+
+	fn eat_punct(&mut self, expected: char) -> bool {
+		let found = self.peek() == Some(&Token::Punct(expected));
+		if found {
+			self.position += 1;
+		}
+		found
+	}
+
+	fn interface(&mut self, source: &'static str, docs: &[String]) -> Result<Interface, Error> {
+		let name = self.word()?.to_string();
+		let Some(address) = doc_tag(docs, "@custom:address") else {
+			return error(format!("Interface {name} has no @custom:address"));
+		};
+		let address = decode_hex(address)?;
+		if address.len() != 20 {
+			return error(format!("Interface {name} has an invalid @custom:address"));
+		}
+
+		let mut interface = Interface {
+			name,
+			address: H160::from_slice(&address),
+			functions: Vec::new(),
+			events: Vec::new(),
+			source,
+		};
+		self.punct('{')?;
+		let mut docs = Vec::new();
+		loop {
+			match self.next() {
+				Some(Token::Doc(line)) => docs.push(line.clone()),
+				Some(Token::Punct('}')) => return Ok(interface),
+				Some(Token::Word(word)) if word == "function" => {
+					interface.functions.push(self.function(&docs)?);
+					docs.clear();
+				}
+				Some(Token::Word(word)) if word == "event" => {
+					interface.events.push(self.event(&docs)?);
+					docs.clear();
+				}
+				token => {
+					return error(format!(
+						"Unsupported member of interface {}: {token:?}",
+						interface.name
+					))
+				}
+			}
+		}
+	}
+
+	fn function(&mut self, docs: &[String]) -> Result<Function, Error> {
+		let name = self.word()?.to_string();
+		let inputs = self.params()?;
+		let mut mutability = Mutability::NonPayable;
+		let mut outputs = Vec::new();
+		loop {
+			match self.next() {
+				Some(Token::Punct(';')) => break,
+				Some(Token::Word(word)) => match word.as_str() {
+					"external" | "virtual" | "override" => {}
+					"pure" => mutability = Mutability::Pure,
+					"view" => mutability = Mutability::View,
+					"payable" => mutability = Mutability::Payable,
+					"returns" => outputs = self.params()?,
+					_ => return error(format!("Unsupported modifier {word} of {name}")),
+				},
+				token => return error(format!("Unexpected {token:?} in function {name}")),
+			}
+		}
+		let documented_selector = match doc_tag(docs, "@custom:selector") {
+			Some(selector) => {
+				let selector: [u8; 4] = decode_hex(selector)?
+					.try_into()
+					.or_else(|_| error(format!("Invalid selector of function {name}")))?;
+				Some(u32::from_be_bytes(selector))
+			}
+			None => None,
+		};
+
+		Ok(Function {
+			name,
+			inputs,
+			outputs,
+			mutability,
+			documented_selector,
+		})
+	}
+
+	fn event(&mut self, docs: &[String]) -> Result<Event, Error> {
+		let name = self.word()?.to_string();
+		let inputs = self.params()?;
+		self.punct(';')?;
+		let documented_selector = match doc_tag(docs, "@custom:selector") {
+			Some(selector) => Some(
+				decode_hex(selector)?
+					.try_into()
+					.or_else(|_| error(format!("Invalid selector of event {name}")))?,
+			),
+			None => None,
+		};
+
+		Ok(Event {
+			name,
+			inputs,
+			documented_selector,
+		})
+	}
+
+	/// Parses a parenthesized list of parameters.
+	fn params(&mut self) -> Result<Vec<Param>, Error> {
+		self.punct('(')?;
+		let mut params = Vec::new();
+		if self.eat_punct(')') {
+			return Ok(params);
+		}
+		loop {
+			params.push(self.param()?);
+			if self.eat_punct(')') {
+				return Ok(params);
+			}
+			self.punct(',')?;
+		}
+	}
+
+	fn param(&mut self) -> Result<Param, Error> {
+		let base = self.word()?;
+		let Some(mut ty) = elementary_type(base) else {
+			return error(format!("Unsupported type {base}"));
+		};
+		while self.eat_punct('[') {
+			ty.push('[');
+			if let Some(Token::Word(length)) = self.peek() {
+				ty.push_str(length);
+				self.position += 1;
+			}
+			self.punct(']')?;
+			ty.push(']');
+		}
+
+		let mut param = Param {
+			name: String::new(),
+			ty,
+			indexed: false,
+		};
+		while let Some(Token::Word(word)) = self.peek() {
+			self.position += 1;
+			match word.as_str() {
+				"memory" | "calldata" => {}
+				"indexed" => param.indexed = true,
+				_ => param.name = word.clone(),
+			}
+		}
+		Ok(param)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Message of the error of parsing `source`.
+	fn parse_error(source: &'static str) -> String {
+		Interface::parse(source)
+			.expect_err("source is malformed")
+			.to_string()
+	}
+
+	/// Source of an interface with the given members.
+	fn interface_with(members: &str) -> &'static str {
+		let source = format!(
+			"/// @custom:address 0x0000000000000000000000000000000000000800\n\
+			 interface Test {{\n{members}\n}}"
+		);
+		Box::leak(source.into_boxed_str())
+	}
+
+	#[test]
+	fn parses_members_around_comments() {
+		let interface = Interface::parse(
+			r#"
+			// SPDX-License-Identifier: GPL-3.0-only
+			pragma solidity >=0.8.3;
+
+			/* A block comment, with a function f(uint256) external; inside. */
+			/// @title Test
+			/// @custom:address 0x0000000000000000000000000000000000000800
+			interface Test {
+				/// @dev Transfers `value` to `to`.
+				/// @custom:selector a9059cbb
+				function transfer(
+					address to, // The recipient.
+					uint256 value
+				) external returns (bool);
+
+				/** Not NatSpec that the parser reads. */
+				/// @custom:selector 70a08231
+				function balanceOf(address owner)
+					external
+					view
+					returns (uint256 balance);
+
+				function hash(bytes memory data) external pure returns (bytes32);
+
+				function deposit() external payable;
+
+				/// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+				event Transfer(address indexed from, address indexed to, uint256 value);
+			}
+			"#,
+		)
+		.unwrap();
+
+		assert_eq!(interface.name, "Test");
+		assert_eq!(interface.address, H160::from_low_u64_be(2048));
+
+		let functions: Vec<_> = interface
+			.functions
+			.iter()
+			.map(|function| {
+				(
+					function.signature(),
+					function.mutability,
+					function.documented_selector,
+				)
+			})
+			.collect();
+		assert_eq!(
+			functions,
+			[
+				(
+					"transfer(address,uint256)".to_string(),
+					Mutability::NonPayable,
+					Some(0xa9059cbb),
+				),
+				(
+					"balanceOf(address)".to_string(),
+					Mutability::View,
+					Some(0x70a08231),
+				),
+				("hash(bytes)".to_string(), Mutability::Pure, None),
+				("deposit()".to_string(), Mutability::Payable, None),
+			]
+		);
+		let transfer = &interface.functions[0];
+		assert_eq!(transfer.selector(), 0xa9059cbb);
+		assert_eq!(transfer.inputs[0].name, "to");
+		assert_eq!(transfer.outputs[0].ty, "bool");
+		assert_eq!(transfer.outputs[0].name, "");
+		assert_eq!(interface.functions[1].outputs[0].name, "balance");
+
+		let [transfer] = &interface.events[..] else {
+			panic!("one event");
+		};
+		assert_eq!(transfer.signature(), "Transfer(address,address,uint256)");
+		assert_eq!(Some(transfer.selector()), transfer.documented_selector);
+		let indexed: Vec<_> = transfer.inputs.iter().map(|param| param.indexed).collect();
+		assert_eq!(indexed, [true, true, false]);
+
+		let abi = interface.abi();
+		assert_eq!(abi[1]["stateMutability"], "view");
+		assert_eq!(abi[3]["stateMutability"], "payable");
+		assert_eq!(abi[4]["type"], "event");
+		assert_eq!(abi[4]["inputs"][0]["indexed"], true);
+	}
+
+	#[test]
+	fn parses_array_types() {
+		let interface = Interface::parse(interface_with(
+			"function f(uint[] calldata a, bytes32[2] memory b, address[][3] c, int8 d) \
+			 external returns (uint256[] memory);",
+		))
+		.unwrap();
+
+		let function = &interface.functions[0];
+		assert_eq!(
+			function.signature(),
+			"f(uint256[],bytes32[2],address[][3],int8)"
+		);
+		assert_eq!(function.outputs[0].ty, "uint256[]");
+	}
+
+	#[test]
+	fn rejects_tuple_types() {
+		// Structs and tuples are not elementary types, whose ABI names the parser would have to
+		// expand.
+		assert_eq!(
+			parse_error(interface_with(
+				"function f((uint256, address) calldata point) external;"
+			)),
+			"Expected a word, found Some(Punct('('))"
+		);
+		assert_eq!(
+			parse_error(interface_with("function f(Point calldata point) external;")),
+			"Unsupported type Point"
+		);
+		assert_eq!(
+			parse_error(interface_with("struct Point { uint256 x; }")),
+			"Unsupported member of interface Test: Some(Word(\"struct\"))"
+		);
+	}
+
+	#[test]
+	fn rejects_malformed_sources() {
+		assert_eq!(
+			parse_error("contract Test {}"),
+			"No interface in the source"
+		);
+		assert_eq!(
+			parse_error("interface Test {}"),
+			"Interface Test has no @custom:address"
+		);
+		assert_eq!(
+			parse_error("/// @custom:address 0x0800\ninterface Test {}"),
+			"Interface Test has an invalid @custom:address"
+		);
+		assert_eq!(
+			parse_error("/// @custom:address 0xzz\ninterface Test {}"),
+			"Invalid hexadecimal value 0xzz"
+		);
+		assert_eq!(
+			parse_error(
+				"/// @custom:address 0x0000000000000000000000000000000000000800\n\
+				 interface A {}\n\
+				 /// @custom:address 0x0000000000000000000000000000000000000801\n\
+				 interface B {}"
+			),
+			"More than one interface in the source"
+		);
+		assert_eq!(
+			parse_error(interface_with("/* function f() external;")),
+			"Unterminated comment"
+		);
+		assert_eq!(
+			parse_error(interface_with("function f() external")),
+			"Unexpected Some(Punct('}')) in function f"
+		);
+		assert_eq!(
+			parse_error(interface_with("function f(uint256 a external;")),
+			"Expected `,`, found Some(Punct(';'))"
+		);
+		assert_eq!(
+			parse_error(interface_with("function f() external nonpayable;")),
+			"Unsupported modifier nonpayable of f"
+		);
+		for ty in ["uint7", "uint264", "bytes33", "bytes0", "fixed128x18"] {
+			assert_eq!(
+				parse_error(interface_with(&format!("function f({ty} a) external;"))),
+				format!("Unsupported type {ty}")
+			);
+		}
+		assert_eq!(
+			parse_error(interface_with(
+				"/// @custom:selector a9059c\nfunction f() external;"
+			)),
+			"Invalid selector of function f"
+		);
+		assert_eq!(
+			parse_error(interface_with("/// @custom:selector 01\nevent E();")),
+			"Invalid selector of event E"
+		);
+		assert_eq!(
+			parse_error(
+				"/// @custom:address 0x0000000000000000000000000000000000000800\n\
+				 interface Test {\nfunction f() external;"
+			),
+			"Unsupported member of interface Test: None"
+		);
+	}
+}
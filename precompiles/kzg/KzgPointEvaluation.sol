@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The KzgPointEvaluation contract's address.
+address constant KZG_POINT_EVALUATION_ADDRESS = 0x000000000000000000000000000000000000000A;
+
+/// @title KZG point evaluation, as specified by EIP-4844
+/// @notice Takes raw input instead of ABI encoded calls. The input is the 32 byte versioned hash,
+/// the evaluation point `z`, the claimed value `y`, the 48 byte commitment and the 48 byte proof.
+/// The output is the number of field elements per blob and the BLS12-381 scalar field modulus as 32
+/// byte words. The call reverts if the proof is invalid.
+/// @custom:address 0x000000000000000000000000000000000000000A
+interface KzgPointEvaluation {}
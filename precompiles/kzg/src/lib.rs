@@ -0,0 +1,132 @@
+//! KZG point evaluation precompile, as specified by EIP-4844.
+//!
+//! The input is a versioned hash, an evaluation point `z`, the claimed value `y`, a commitment
+//! and a proof, in that order. The call succeeds, returning the number of field elements per blob
+//! and the BLS12-381 scalar field modulus, only when the commitment hashes to the versioned hash
+//! and the proof shows that the committed polynomial evaluates to `y` at `z`. Any other input
+//! reverts with all the gas consumed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInt, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use fp_evm::{
+	ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use hex_literal::hex;
+
+/// `[τ]₂`, the second G2 point of the Ethereum KZG ceremony output and the only element of the
+/// trusted setup that proof verification needs.
+const TAU_G2: [u8; 96] = hex!(
+	"b5bfd7dd8cdeb128843bc287230af38926187075cbfbefa81009a2ce615ac53d2914e5870cb452d2afaaab24f3499f72"
+	"185cbfee53492714734429b7b38608e23926c911cceceac9a36851477ba4c60b087041de621000edc98edada20c1def2"
+);
+
+/// `FIELD_ELEMENTS_PER_BLOB` followed by `BLS_MODULUS`, both as 32 byte big endian words.
+const RETURN_VALUE: [u8; 64] = hex!(
+	"0000000000000000000000000000000000000000000000000000000000001000"
+	"73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+);
+
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// Verification of a KZG proof for the evaluation of a blob polynomial at one point.
+pub struct KzgPointEvaluation;
+
+impl KzgPointEvaluation {
+	const GAS_COST: u64 = 50_000;
+	const INPUT_LENGTH: usize = 192;
+
+	fn verify(input: &[u8]) -> Result<(), &'static str> {
+		if input.len() != Self::INPUT_LENGTH {
+			return Err("invalid input length");
+		}
+		let (versioned_hash, input) = input.split_at(32);
+		let (z, input) = input.split_at(32);
+		let (y, input) = input.split_at(32);
+		let (commitment, proof) = input.split_at(48);
+
+		let mut hash = sp_io::hashing::sha2_256(commitment);
+		hash[0] = VERSIONED_HASH_VERSION_KZG;
+		if hash != versioned_hash {
+			return Err("mismatched versioned hash");
+		}
+
+		let z = decode_scalar(z)?;
+		let y = decode_scalar(y)?;
+		let commitment = decode_g1(commitment).ok_or("invalid commitment")?;
+		let proof = decode_g1(proof).ok_or("invalid proof")?;
+		let tau = G2Affine::deserialize_compressed(&TAU_G2[..])
+			.expect("the trusted setup point is a valid G2 point; qed");
+
+		// e(commitment - [y]₁, [1]₂) = e(proof, [τ]₂ - [z]₂)
+		let g1 = G1Affine::generator();
+		let g2 = G2Affine::generator();
+		let lhs = G1Projective::from(commitment) - g1 * y;
+		let rhs = G2Projective::from(tau) - g2 * z;
+		let check = Bls12_381::multi_pairing([lhs.into_affine(), -proof], [g2, rhs.into_affine()]);
+		if !check.is_zero() {
+			return Err("verify kzg proof failed");
+		}
+
+		Ok(())
+	}
+}
+
+impl Precompile for KzgPointEvaluation {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Self::GAS_COST)?;
+
+		Self::verify(handle.input()).map_err(|error| PrecompileFailure::Error {
+			exit_status: ExitError::Other(error.into()),
+		})?;
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: Vec::from(RETURN_VALUE),
+		})
+	}
+}
+
+/// Decodes a big endian scalar, rejecting values that are not below the modulus.
+fn decode_scalar(bytes: &[u8]) -> Result<Fr, &'static str> {
+	let mut limbs = [0u64; 4];
+	for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+		*limb = u64::from_be_bytes(chunk.try_into().expect("chunks are 8 bytes long; qed"));
+	}
+	Fr::from_bigint(BigInt(limbs)).ok_or("field element is not canonical")
+}
+
+/// Decodes a compressed G1 point, checking that it is on the curve and in the subgroup.
+///
+/// arkworks ignores the bytes that follow the infinity flag, where the spec requires them to be
+/// zero, so the point must also encode back to the input.
+fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+	let point = G1Affine::deserialize_compressed(bytes).ok()?;
+	let mut encoded = [0u8; 48];
+	point.serialize_compressed(&mut encoded[..]).ok()?;
+	(encoded[..] == *bytes).then_some(point)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::{
+		test_precompile_failure_test_vectors, test_precompile_test_vectors,
+	};
+
+	#[test]
+	fn point_evaluation_matches_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<KzgPointEvaluation>("../testdata/kzgPointEvaluation.json")?;
+		test_precompile_failure_test_vectors::<KzgPointEvaluation>(
+			"../testdata/fail-kzgPointEvaluation.json",
+		)
+	}
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The NominationPools contract's address.
+address constant NOMINATION_POOLS_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+/// @dev The NominationPools contract's instance.
+NominationPools constant NOMINATION_POOLS_CONTRACT = NominationPools(
+    NOMINATION_POOLS_ADDRESS
+);
+
+/// @title Nomination pools precompile
+/// @notice Stake less than the minimum nominator bond by joining a nomination pool, claim the
+/// rewards of the pool and leave it. Pools are created and managed with extrinsics.
+/// @dev Pool states are encoded as 0 = DoesNotExist, 1 = Open, 2 = Blocked, 3 = Destroying.
+/// Amounts are in the smallest unit of IPT (18 decimals).
+/// @custom:address 0x0000000000000000000000000000000000000807
+interface NominationPools {
+    /// @dev Join the pool `poolId`, bonding `amount` of the caller's free balance.
+    /// @custom:selector 98f15d02
+    function join(uint256 amount, uint32 poolId) external;
+
+    /// @dev Bond more of the caller's free balance into its pool.
+    /// @custom:selector eaca88de
+    function bondExtra(uint256 amount) external;
+
+    /// @dev Bond the pending rewards of the caller into its pool.
+    /// @custom:selector 026db81f
+    function bondExtraRewards() external;
+
+    /// @dev Pay out the pending rewards of the caller.
+    /// @custom:selector e88499a9
+    function claimPayout() external;
+
+    /// @dev Schedule `points` of the caller's points to be unbonded after the bonding duration.
+    /// Pending rewards are paid out first.
+    /// @custom:selector 27de9e32
+    function unbond(uint256 points) external;
+
+    /// @dev Withdraw the unbonded funds of the caller whose bonding duration has passed, and
+    /// leave the pool once all of them are withdrawn.
+    /// @custom:selector 548a6706
+    function withdrawUnbonded(uint32 numSlashingSpans) external;
+
+    /// @dev The rewards `member` can claim, zero if it is not in a pool.
+    /// @custom:selector 31d7a262
+    function pendingRewards(address member) external view returns (uint256);
+
+    /// @dev The pool membership of an account, all zero if it is not in a pool.
+    /// @return poolId The pool of the account.
+    /// @return points Points of the account that are actively bonded.
+    /// @return unbondingPoints Points of the account that are being unbonded.
+    /// @custom:selector e7d4539e
+    function member(address account)
+        external
+        view
+        returns (
+            uint32 poolId,
+            uint256 points,
+            uint256 unbondingPoints
+        );
+
+    /// @dev A bonded pool.
+    /// @return state The state of the pool.
+    /// @return points Points issued by the pool.
+    /// @return memberCount Number of members of the pool.
+    /// @custom:selector 53463ebf
+    function pool(uint32 poolId)
+        external
+        view
+        returns (
+            uint8 state,
+            uint256 points,
+            uint32 memberCount
+        );
+
+    /// @dev The balance `points` of the pool `poolId` are worth.
+    /// @custom:selector 4cf2e083
+    function pointsToBalance(uint32 poolId, uint256 points)
+        external
+        view
+        returns (uint256);
+
+    /// @dev The points `amount` would be issued if bonded into the pool `poolId`.
+    /// @custom:selector 9eba1a46
+    function balanceToPoints(uint32 poolId, uint256 amount)
+        external
+        view
+        returns (uint256);
+
+    /// @dev The minimum amount to bond to join a pool.
+    /// @custom:selector f0c2aa59
+    function minJoinBond() external view returns (uint256);
+
+    /// @dev The id of the last pool created.
+    /// @custom:selector a657e579
+    function lastPoolId() external view returns (uint32);
+}
@@ -0,0 +1,256 @@
+//! Precompile to interact with `pallet-nomination-pools` from the EVM.
+//!
+//! It lets an Ethereum account stake less than the minimum nominator bond by joining a pool,
+//! then claim its share of the rewards and leave. Calls are dispatched as signed extrinsics of
+//! the caller, so the gas charged is derived from the benchmarked weight of the underlying call
+//! and refunded down to its actual weight. Pools are created and managed with extrinsics.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use pallet_nomination_pools::{BalanceOf, BondExtra, PoolId, PoolState};
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+
+/// Pool states as encoded in the Solidity interface.
+const STATE_DOES_NOT_EXIST: u8 = 0;
+const STATE_OPEN: u8 = 1;
+const STATE_BLOCKED: u8 = 2;
+const STATE_DESTROYING: u8 = 3;
+
+/// Exposes joining, bonding more, claiming and leaving nomination pools to Ethereum accounts.
+pub struct NominationPoolsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> NominationPoolsPrecompile<Runtime>
+where
+	Runtime: pallet_nomination_pools::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_nomination_pools::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	/// Calls are dispatched on behalf of the caller, so a contract must not be able to run this
+	/// precompile in its own context and join, bond or withdraw for the account that called it.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	#[precompile::public("join(uint256,uint32)")]
+	fn join(handle: &mut impl PrecompileHandle, amount: U256, pool_id: PoolId) -> EvmResult {
+		let amount = Self::u256_to_balance(amount, "amount")?;
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::join { amount, pool_id },
+		)
+	}
+
+	#[precompile::public("bondExtra(uint256)")]
+	fn bond_extra(handle: &mut impl PrecompileHandle, amount: U256) -> EvmResult {
+		let amount = Self::u256_to_balance(amount, "amount")?;
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::bond_extra {
+				extra: BondExtra::FreeBalance(amount),
+			},
+		)
+	}
+
+	#[precompile::public("bondExtraRewards()")]
+	fn bond_extra_rewards(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::bond_extra {
+				extra: BondExtra::Rewards,
+			},
+		)
+	}
+
+	#[precompile::public("claimPayout()")]
+	fn claim_payout(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::claim_payout {},
+		)
+	}
+
+	#[precompile::public("unbond(uint256)")]
+	fn unbond(handle: &mut impl PrecompileHandle, points: U256) -> EvmResult {
+		let unbonding_points = Self::u256_to_balance(points, "points")?;
+		let member_account = Self::caller(handle);
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::unbond {
+				member_account,
+				unbonding_points,
+			},
+		)
+	}
+
+	#[precompile::public("withdrawUnbonded(uint32)")]
+	fn withdraw_unbonded(handle: &mut impl PrecompileHandle, num_slashing_spans: u32) -> EvmResult {
+		let member_account = Self::caller(handle);
+		Self::dispatch(
+			handle,
+			pallet_nomination_pools::Call::<Runtime>::withdraw_unbonded {
+				member_account,
+				num_slashing_spans,
+			},
+		)
+	}
+
+	#[precompile::public("pendingRewards(address)")]
+	#[precompile::view]
+	fn pending_rewards(handle: &mut impl PrecompileHandle, member: Address) -> EvmResult<U256> {
+		// PoolMembers, BondedPools, RewardPools, the reward account
+		handle.record_cost(4 * RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(
+			pallet_nomination_pools::Pallet::<Runtime>::api_pending_rewards(Self::account(member))
+				.map(Into::into)
+				.unwrap_or_default(),
+		)
+	}
+
+	#[precompile::public("member(address)")]
+	#[precompile::view]
+	fn member(
+		handle: &mut impl PrecompileHandle,
+		member: Address,
+	) -> EvmResult<(PoolId, U256, U256)> {
+		// PoolMembers
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Some(member) =
+			pallet_nomination_pools::PoolMembers::<Runtime>::get(Self::account(member))
+		else {
+			return Ok(Default::default());
+		};
+		let unbonding_points = member
+			.unbonding_eras
+			.values()
+			.fold(U256::zero(), |total, points| {
+				total.saturating_add((*points).into())
+			});
+
+		Ok((member.pool_id, member.points.into(), unbonding_points))
+	}
+
+	#[precompile::public("pool(uint32)")]
+	#[precompile::view]
+	fn pool(handle: &mut impl PrecompileHandle, pool_id: PoolId) -> EvmResult<(u8, U256, u32)> {
+		// BondedPools
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Some(pool) = pallet_nomination_pools::BondedPools::<Runtime>::get(pool_id) else {
+			return Ok((STATE_DOES_NOT_EXIST, U256::zero(), 0));
+		};
+		let state = match pool.state {
+			PoolState::Open => STATE_OPEN,
+			PoolState::Blocked => STATE_BLOCKED,
+			PoolState::Destroying => STATE_DESTROYING,
+		};
+
+		Ok((state, pool.points.into(), pool.member_counter))
+	}
+
+	#[precompile::public("pointsToBalance(uint32,uint256)")]
+	#[precompile::view]
+	fn points_to_balance(
+		handle: &mut impl PrecompileHandle,
+		pool_id: PoolId,
+		points: U256,
+	) -> EvmResult<U256> {
+		// BondedPools, and the Bonded and Ledger entries of the pool in staking
+		handle.record_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let points = Self::u256_to_balance(points, "points")?;
+		Ok(
+			pallet_nomination_pools::Pallet::<Runtime>::api_points_to_balance(pool_id, points)
+				.into(),
+		)
+	}
+
+	#[precompile::public("balanceToPoints(uint32,uint256)")]
+	#[precompile::view]
+	fn balance_to_points(
+		handle: &mut impl PrecompileHandle,
+		pool_id: PoolId,
+		amount: U256,
+	) -> EvmResult<U256> {
+		// BondedPools, and the Bonded and Ledger entries of the pool in staking
+		handle.record_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let amount = Self::u256_to_balance(amount, "amount")?;
+		Ok(
+			pallet_nomination_pools::Pallet::<Runtime>::api_balance_to_points(pool_id, amount)
+				.into(),
+		)
+	}
+
+	#[precompile::public("minJoinBond()")]
+	#[precompile::view]
+	fn min_join_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// MinJoinBond
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_nomination_pools::MinJoinBond::<Runtime>::get().into())
+	}
+
+	#[precompile::public("lastPoolId()")]
+	#[precompile::view]
+	fn last_pool_id(handle: &mut impl PrecompileHandle) -> EvmResult<PoolId> {
+		// LastPoolId
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_nomination_pools::LastPoolId::<Runtime>::get())
+	}
+}
+
+impl<Runtime> NominationPoolsPrecompile<Runtime>
+where
+	Runtime: pallet_nomination_pools::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_nomination_pools::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		call: impl Into<Runtime::RuntimeCall>,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call.into())?;
+
+		Ok(())
+	}
+
+	fn account(address: Address) -> Runtime::AccountId {
+		Runtime::AddressMapping::into_account_id(address.into())
+	}
+
+	/// The caller, as the member account argument of the pool calls.
+	fn caller(
+		handle: &impl PrecompileHandle,
+	) -> <<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source {
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		<Runtime as frame_system::Config>::Lookup::unlookup(caller)
+	}
+
+	fn u256_to_balance(value: U256, field: &'static str) -> EvmResult<BalanceOf<Runtime>> {
+		value.try_into().map_err(|_| {
+			RevertReason::value_is_too_large("balance type")
+				.in_field(field)
+				.into()
+		})
+	}
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The P256Verify contract's address.
+address constant P256_VERIFY_ADDRESS = 0x0000000000000000000000000000000000000100;
+
+/// @title secp256r1 signature verification, as specified by RIP-7212
+/// @notice Takes raw input instead of ABI encoded calls. The input is the 32 byte message hash
+/// followed by the `r`, `s`, `x` and `y` words of the signature and public key. The output is a 32
+/// byte word holding 1 if the signature is valid, and no data otherwise.
+/// @custom:address 0x0000000000000000000000000000000000000100
+interface P256Verify {}
@@ -0,0 +1,73 @@
+//! secp256r1 (P-256) signature verification precompile, as specified by RIP-7212.
+//!
+//! The input is the 32 byte message hash followed by the `r`, `s`, `x` and `y` words of the
+//! signature and public key. A valid signature returns 1 as a 32 byte word. Any other input,
+//! including a malformed one, returns no data and still costs the full gas: the call never
+//! reverts. As in the RIP, signatures with a high `s` are not rejected.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use fp_evm::{ExitSucceed, Precompile, PrecompileHandle, PrecompileOutput, PrecompileResult};
+use p256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+/// P-256 ECDSA verification of a prehashed message.
+pub struct P256Verify;
+
+impl P256Verify {
+	const GAS_COST: u64 = 3_450;
+	const INPUT_LENGTH: usize = 160;
+
+	fn verify(input: &[u8]) -> bool {
+		if input.len() != Self::INPUT_LENGTH {
+			return false;
+		}
+		let (hash, input) = input.split_at(32);
+		let (signature, key) = input.split_at(64);
+
+		// Rejects `r` or `s` out of `[1, n)`.
+		let Ok(signature) = Signature::from_slice(signature) else {
+			return false;
+		};
+
+		// Rejects coordinates out of the field, points off the curve and the identity.
+		let mut point = [0u8; 65];
+		point[0] = 0x04;
+		point[1..].copy_from_slice(key);
+		let Ok(key) = VerifyingKey::from_sec1_bytes(&point) else {
+			return false;
+		};
+
+		key.verify_prehash(hash, &signature).is_ok()
+	}
+}
+
+impl Precompile for P256Verify {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(Self::GAS_COST)?;
+
+		let output = if Self::verify(handle.input()) {
+			let mut output = Vec::from([0u8; 32]);
+			output[31] = 1;
+			output
+		} else {
+			Vec::new()
+		};
+
+		Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::test_precompile_test_vectors;
+
+	#[test]
+	fn p256_verify_matches_wycheproof_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<P256Verify>("../testdata/p256Verify.json")
+	}
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Poseidon contract's address.
+address constant POSEIDON_ADDRESS = 0x0000000000000000000000000000000000000412;
+
+/// @title Poseidon hash over the BN254 scalar field, compatible with circomlib
+/// @notice Takes raw input instead of ABI encoded calls. The input is 1 to 16 field elements, each
+/// a 32 byte big endian word below the field modulus. The output is their 32 byte hash.
+/// @custom:address 0x0000000000000000000000000000000000000412
+interface Poseidon {}
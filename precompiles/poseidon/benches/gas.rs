@@ -0,0 +1,55 @@
+//! Times the Poseidon precompile on its success test vectors and prints the gas charged per
+//! microsecond of execution.
+//!
+//! Run with `cargo bench -p pallet-evm-precompile-poseidon` next to
+//! `cargo bench -p pallet-evm-precompile-curves`: no arity should be charged fewer gas per
+//! microsecond than the BLS12-381 precompiles.
+
+use std::time::{Duration, Instant};
+
+use fp_evm::{Context, Precompile};
+use pallet_evm_precompile_poseidon::Poseidon;
+use pallet_evm_test_vector_support::MockHandle;
+use serde::Deserialize;
+use sp_core::{H160, U256};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TestVector {
+	input: String,
+	name: String,
+	gas: u64,
+}
+
+fn main() {
+	let vectors: Vec<TestVector> = serde_json::from_str(
+		&std::fs::read_to_string("../testdata/poseidon.json").expect("test vectors exist"),
+	)
+	.expect("test vectors are valid");
+
+	for vector in vectors {
+		let input = hex::decode(&vector.input).expect("input is hex encoded");
+		let context = Context {
+			address: H160::zero(),
+			caller: H160::zero(),
+			apparent_value: U256::zero(),
+		};
+
+		let start = Instant::now();
+		let mut iterations = 0u32;
+		while start.elapsed() < Duration::from_millis(500) {
+			let mut handle = MockHandle::new(input.clone(), None, context.clone());
+			Poseidon::execute(&mut handle).expect("test vector succeeds");
+			iterations += 1;
+		}
+		let micros = start.elapsed().as_secs_f64() * 1e6 / f64::from(iterations);
+
+		println!(
+			"{:<48} {:>9} gas {:>12.1} us {:>8.1} gas/us",
+			vector.name,
+			vector.gas,
+			micros,
+			vector.gas as f64 / micros,
+		);
+	}
+}
@@ -0,0 +1,139 @@
+//! Poseidon hash precompile over the BN254 scalar field, compatible with circomlib.
+//!
+//! The input is 1 to 16 field elements, each a 32 byte big endian word below the field modulus,
+//! and the output is `poseidon(inputs)` as a 32 byte word, the same value circomlib's `Poseidon`
+//! circuit and circomlibjs compute. The permutation uses the x^5 S-box, 8 full rounds and
+//! circomlib's round constants and MDS matrices for a state of one capacity element plus the
+//! inputs.
+//!
+//! Gas is charged per arity. Each entry of [`Poseidon::GAS_COSTS`] is the benchmarked time of
+//! that arity priced at 22 gas per microsecond, the highest rate measured for the EIP-2537
+//! BLS12-381 precompiles on the same machine. `benches/gas.rs` reports the gas per microsecond
+//! of every success test vector.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod constants;
+
+use alloc::vec::Vec;
+
+use ark_bn254::Fr;
+use ark_ff::{BigInt, BigInteger, Field, PrimeField};
+use fp_evm::{
+	ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+
+const FULL_ROUNDS: usize = 8;
+
+/// circomlib-compatible Poseidon hash of 1 to 16 field elements.
+pub struct Poseidon;
+
+impl Poseidon {
+	/// Gas charged for `n` inputs, at index `n - 1`.
+	pub const GAS_COSTS: [u64; 16] = [
+		550, 890, 1_390, 2_260, 3_050, 4_170, 5_340, 7_330, 7_730, 10_460, 12_480, 14_330, 19_150,
+		19_970, 23_030, 24_790,
+	];
+
+	fn arity(input: &[u8]) -> Result<usize, &'static str> {
+		let arity = input.len() / 32;
+		if input.len() % 32 != 0 || !(1..=Self::GAS_COSTS.len()).contains(&arity) {
+			return Err("invalid input length");
+		}
+		Ok(arity)
+	}
+
+	fn hash(input: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let inputs = input
+			.chunks_exact(32)
+			.map(decode_scalar)
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(permute(&inputs).into_bigint().to_bytes_be())
+	}
+}
+
+impl Precompile for Poseidon {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let arity = Self::arity(handle.input()).map_err(failure)?;
+		handle.record_cost(Self::GAS_COSTS[arity - 1])?;
+
+		let output = Self::hash(handle.input()).map_err(failure)?;
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output,
+		})
+	}
+}
+
+fn failure(error: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Error {
+		exit_status: ExitError::Other(error.into()),
+	}
+}
+
+/// Runs the permutation on `[0, inputs..]` and returns the first element of the state.
+fn permute(inputs: &[Fr]) -> Fr {
+	let width = inputs.len() + 1;
+	let (partial_rounds, constants, matrix) = constants::parameters(inputs.len());
+	let first_partial_round = FULL_ROUNDS / 2;
+	let last_partial_round = first_partial_round + partial_rounds;
+
+	let mut state = Vec::with_capacity(width);
+	state.push(Fr::from(0u8));
+	state.extend_from_slice(inputs);
+	let mut mixed = state.clone();
+
+	for (round, constants) in constants.chunks_exact(width).enumerate() {
+		for (element, constant) in state.iter_mut().zip(constants) {
+			*element += constant;
+		}
+
+		if (first_partial_round..last_partial_round).contains(&round) {
+			state[0] = sbox(state[0]);
+		} else {
+			state
+				.iter_mut()
+				.for_each(|element| *element = sbox(*element));
+		}
+
+		for (element, row) in mixed.iter_mut().zip(matrix.chunks_exact(width)) {
+			*element = row.iter().zip(&state).map(|(m, s)| *m * s).sum();
+		}
+		core::mem::swap(&mut state, &mut mixed);
+	}
+
+	state[0]
+}
+
+fn sbox(element: Fr) -> Fr {
+	let square = element.square();
+	square.square() * element
+}
+
+/// Decodes a big endian scalar, rejecting values that are not below the modulus.
+fn decode_scalar(bytes: &[u8]) -> Result<Fr, &'static str> {
+	let mut limbs = [0u64; 4];
+	for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+		*limb = u64::from_be_bytes(chunk.try_into().expect("chunks are 8 bytes long; qed"));
+	}
+	Fr::from_bigint(BigInt(limbs)).ok_or("field element is not canonical")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::{
+		test_precompile_failure_test_vectors, test_precompile_test_vectors,
+	};
+
+	#[test]
+	fn poseidon_matches_circomlib_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Poseidon>("../testdata/poseidon.json")?;
+		test_precompile_failure_test_vectors::<Poseidon>("../testdata/fail-poseidon.json")
+	}
+}
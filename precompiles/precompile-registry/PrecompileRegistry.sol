@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The PrecompileRegistry contract's address.
+address constant PRECOMPILE_REGISTRY_ADDRESS = 0x0000000000000000000000000000000000000804;
+
+/// @dev The PrecompileRegistry contract's instance.
+PrecompileRegistry constant PRECOMPILE_REGISTRY_CONTRACT = PrecompileRegistry(
+    PRECOMPILE_REGISTRY_ADDRESS
+);
+
+/// @title Precompile registry
+/// @notice Check whether an address is a precompile of the runtime and whether calls to it run.
+/// Governance can deprecate or disable a precompile without a runtime upgrade, except for the
+/// Ethereum precompiles, which the registry does not manage and which always run.
+/// @custom:address 0x0000000000000000000000000000000000000804
+interface PrecompileRegistry {
+    /// @dev Whether there is a precompile of the runtime at `a`, whatever its status.
+    /// @custom:selector 446b450e
+    function isPrecompile(address a) external view returns (bool);
+
+    /// @dev Whether there is a precompile at `a` that is not disabled. The precompiles
+    /// that the registry does not manage are always active.
+    /// @custom:selector 6f5e23cf
+    function isActivePrecompile(address a) external view returns (bool);
+
+    /// @dev Whether there is a precompile at `a` that is going away.
+    /// @custom:selector 820ecca8
+    function isDeprecatedPrecompile(address a) external view returns (bool);
+}
@@ -0,0 +1,81 @@
+//! Precompile to read the precompile registry, so that contracts can check whether an address is
+//! a precompile of the runtime and whether calls to it run.
+//!
+//! The precompiles that the registry does not manage, such as the Ethereum ones, always run.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, IsPrecompileResult, PrecompileFailure, PrecompileHandle};
+use pallet_evm::PrecompileSet;
+use pallet_precompile_registry::{Pallet as Registry, PrecompileStatus};
+use precompile_utils::prelude::*;
+use sp_core::{Get, H160};
+
+/// Reads the status of the runtime's precompiles.
+pub struct PrecompileRegistryPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> PrecompileRegistryPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_precompile_registry::Config,
+{
+	#[precompile::public("isPrecompile(address)")]
+	#[precompile::view]
+	fn is_precompile(handle: &mut impl PrecompileHandle, address: Address) -> EvmResult<bool> {
+		Self::in_precompile_set(handle, address.into())
+	}
+
+	#[precompile::public("isActivePrecompile(address)")]
+	#[precompile::view]
+	fn is_active_precompile(
+		handle: &mut impl PrecompileHandle,
+		address: Address,
+	) -> EvmResult<bool> {
+		Ok(Self::status(handle, address.into())?.is_some_and(|status| status.is_active()))
+	}
+
+	#[precompile::public("isDeprecatedPrecompile(address)")]
+	#[precompile::view]
+	fn is_deprecated_precompile(
+		handle: &mut impl PrecompileHandle,
+		address: Address,
+	) -> EvmResult<bool> {
+		Ok(Self::status(handle, address.into())? == Some(PrecompileStatus::Deprecated))
+	}
+}
+
+impl<Runtime> PrecompileRegistryPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_precompile_registry::Config,
+{
+	/// Whether `address` is one of the runtime's precompiles, as the EVM sees them.
+	fn in_precompile_set(handle: &mut impl PrecompileHandle, address: H160) -> EvmResult<bool> {
+		match Runtime::PrecompilesValue::get().is_precompile(address, handle.remaining_gas()) {
+			IsPrecompileResult::Answer { is_precompile, .. } => Ok(is_precompile),
+			IsPrecompileResult::OutOfGas => Err(PrecompileFailure::Error {
+				exit_status: ExitError::OutOfGas,
+			}),
+		}
+	}
+
+	/// Status of the precompile at `address`, `None` if there is none. The precompiles that the
+	/// registry does not manage are always enabled.
+	fn status(
+		handle: &mut impl PrecompileHandle,
+		address: H160,
+	) -> EvmResult<Option<PrecompileStatus>> {
+		if !Self::in_precompile_set(handle, address)? {
+			return Ok(None);
+		}
+		if !Registry::<Runtime>::is_precompile(address) {
+			return Ok(Some(PrecompileStatus::Enabled));
+		}
+
+		// Statuses
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(Some(Registry::<Runtime>::status(address)))
+	}
+}
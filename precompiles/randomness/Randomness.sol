@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Randomness contract's address.
+address constant RANDOMNESS_ADDRESS = 0x0000000000000000000000000000000000000806;
+
+/// @dev The Randomness contract's instance.
+Randomness constant RANDOMNESS_CONTRACT = Randomness(RANDOMNESS_ADDRESS);
+
+/// @title Randomness precompile
+/// @notice Read the BABE randomness of an epoch, which is known to everyone from the start of
+/// the epoch, or request random words from the VRF output of a later block. A request reserves
+/// `requestDeposit()` from the caller, returned when the caller fulfils it. A request that is
+/// not fulfilled before it expires can be purged by anyone, who gets the deposit.
+/// @dev Request statuses are encoded as 0 = DoesNotExist, 1 = Pending, 2 = Ready, 3 = Expired.
+/// @custom:address 0x0000000000000000000000000000000000000806
+interface Randomness {
+    /// @dev The BABE randomness of the current epoch.
+    /// @custom:selector 5bb76260
+    function currentEpochRandomness() external view returns (bytes32);
+
+    /// @dev The BABE randomness of the previous epoch.
+    /// @custom:selector e27233a7
+    function previousEpochRandomness() external view returns (bytes32);
+
+    /// @dev The deposit reserved for each request.
+    /// @custom:selector b03c67aa
+    function requestDeposit() external view returns (uint256);
+
+    /// @dev The status of a request.
+    /// @custom:selector 1a313202
+    function requestStatus(uint256 requestId) external view returns (uint8);
+
+    /// @dev Request `numWords` random words from the VRF output of the block `delay` blocks
+    /// from now.
+    /// @param salt Mixed into the words, so that requests for the same block differ.
+    /// @return requestId The identifier of the request.
+    /// @custom:selector ee28927f
+    function requestRandomness(
+        bytes32 salt,
+        uint8 numWords,
+        uint32 delay
+    ) external returns (uint256 requestId);
+
+    /// @dev Get the random words of a ready request of the caller and its deposit back.
+    /// @custom:selector be3b0352
+    function fulfilRequest(uint256 requestId)
+        external
+        returns (bytes32[] memory randomWords);
+
+    /// @dev Remove an expired request and get its deposit.
+    /// @custom:selector 1d26cbab
+    function purgeExpiredRequest(uint256 requestId) external;
+}
@@ -0,0 +1,139 @@
+//! Precompile to read BABE randomness from contracts.
+//!
+//! The epoch randomness of BABE is known to everyone from the start of an epoch, so it suits
+//! uses where the result does not need to be hidden until after a commitment. A contract that
+//! needs that, such as a lottery, requests random words from the VRF output of a later block,
+//! which reserves a deposit from it, then fulfils its request once that block has been produced
+//! to get the words and its deposit back. See `pallet-randomness` for the details.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use pallet_randomness::{BalanceOf, Pallet as Randomness, RequestId, RequestStatus};
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_std::vec::Vec;
+
+/// Exposes the randomness of `pallet-randomness`.
+pub struct RandomnessPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> RandomnessPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config + pallet_randomness::Config,
+	BalanceOf<Runtime>: Into<U256>,
+	BlockNumberFor<Runtime>: From<u32>,
+{
+	/// Requests and deposits belong to the caller, so a contract must not be able to run the
+	/// precompile in its own context and act as the account that called it.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	#[precompile::public("currentEpochRandomness()")]
+	#[precompile::view]
+	fn current_epoch_randomness(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// Epoch
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(Randomness::<Runtime>::current_epoch_randomness().into())
+	}
+
+	#[precompile::public("previousEpochRandomness()")]
+	#[precompile::view]
+	fn previous_epoch_randomness(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// Epoch
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(Randomness::<Runtime>::previous_epoch_randomness().into())
+	}
+
+	#[precompile::public("requestDeposit()")]
+	#[precompile::view]
+	fn request_deposit(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		Ok(<Runtime as pallet_randomness::Config>::Deposit::get().into())
+	}
+
+	/// 0 if the request does not exist, 1 if it is pending, 2 if it can be fulfilled and 3 if it
+	/// expired.
+	#[precompile::public("requestStatus(uint256)")]
+	#[precompile::view]
+	fn request_status(handle: &mut impl PrecompileHandle, request_id: U256) -> EvmResult<u8> {
+		// Requests, Results
+		handle.record_cost(2 * RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Ok(request_id) = RequestId::try_from(request_id) else {
+			return Ok(0);
+		};
+		Ok(match Randomness::<Runtime>::request_status(request_id) {
+			RequestStatus::DoesNotExist => 0,
+			RequestStatus::Pending => 1,
+			RequestStatus::Ready => 2,
+			RequestStatus::Expired => 3,
+		})
+	}
+
+	#[precompile::public("requestRandomness(bytes32,uint8,uint32)")]
+	fn request_randomness(
+		handle: &mut impl PrecompileHandle,
+		salt: H256,
+		num_words: u8,
+		delay: u32,
+	) -> EvmResult<U256> {
+		// Reads NextRequestId, Number, Results, AwaitingBlocks and the requester's account, and
+		// writes them back but Number, with the request.
+		handle.record_cost(
+			5 * RuntimeHelper::<Runtime>::db_read_gas_cost()
+				+ 5 * RuntimeHelper::<Runtime>::db_write_gas_cost(),
+		)?;
+
+		let requester = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let request_id =
+			Randomness::<Runtime>::request_randomness(requester, salt, num_words, delay.into())
+				.map_err(|error| revert(<&'static str>::from(error)))?;
+
+		Ok(request_id.into())
+	}
+
+	#[precompile::public("fulfilRequest(uint256)")]
+	fn fulfil_request(
+		handle: &mut impl PrecompileHandle,
+		request_id: U256,
+	) -> EvmResult<Vec<H256>> {
+		// Requests, Results, the requester's account
+		handle.record_cost(
+			3 * RuntimeHelper::<Runtime>::db_read_gas_cost()
+				+ 3 * RuntimeHelper::<Runtime>::db_write_gas_cost(),
+		)?;
+
+		let request_id = RequestId::try_from(request_id).map_err(|_| revert("RequestNotFound"))?;
+		let requester = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		Randomness::<Runtime>::fulfil_request(&requester, request_id)
+			.map_err(|error| revert(<&'static str>::from(error)))
+	}
+
+	#[precompile::public("purgeExpiredRequest(uint256)")]
+	fn purge_expired_request(handle: &mut impl PrecompileHandle, request_id: U256) -> EvmResult {
+		// Requests, Results, the requester's and the purger's accounts
+		handle.record_cost(
+			4 * RuntimeHelper::<Runtime>::db_read_gas_cost()
+				+ 4 * RuntimeHelper::<Runtime>::db_write_gas_cost(),
+		)?;
+
+		let request_id = RequestId::try_from(request_id).map_err(|_| revert("RequestNotFound"))?;
+		let purger = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		Randomness::<Runtime>::purge_expired_request(purger, request_id)
+			.map_err(|error| revert(<&'static str>::from(error)))
+	}
+}
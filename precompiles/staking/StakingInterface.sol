@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Staking contract's address.
+address constant STAKING_ADDRESS = 0x0000000000000000000000000000000000000800;
+
+/// @dev The Staking contract's instance.
+Staking constant STAKING_CONTRACT = Staking(STAKING_ADDRESS);
+
+/// @title Pallet Staking Interface
+/// @notice Bond, nominate and validate from an Ethereum account.
+/// @dev Reward destinations are encoded as 0 = Staked, 1 = Stash, 2 = Account, 3 = None.
+/// The `payee` argument is only read for the Account destination.
+/// Amounts are in the smallest unit of IPT (18 decimals).
+/// @custom:address 0x0000000000000000000000000000000000000800
+interface Staking {
+    /// @dev Bond `value` of the caller's balance, making the caller a stash account.
+    /// @custom:selector 70c48766
+    function bond(
+        uint256 value,
+        uint8 rewardDestination,
+        address payee
+    ) external;
+
+    /// @dev Bond more of the caller's free balance.
+    /// @custom:selector eaca88de
+    function bondExtra(uint256 maxAdditional) external;
+
+    /// @dev Schedule `value` to be unbonded after the bonding duration.
+    /// @custom:selector 27de9e32
+    function unbond(uint256 value) external;
+
+    /// @dev Withdraw unbonded chunks whose bonding duration has passed.
+    /// @custom:selector 548a6706
+    function withdrawUnbonded(uint32 numSlashingSpans) external;
+
+    /// @dev Nominate the given validator stashes.
+    /// @custom:selector 19f2fdad
+    function nominate(address[] memory targets) external;
+
+    /// @dev Stop nominating or validating.
+    /// @custom:selector 2b8a3ae6
+    function chill() external;
+
+    /// @dev Declare the intention to validate.
+    /// @param commission Commission in parts per billion.
+    /// @param blocked Whether the validator refuses new nominations.
+    /// @custom:selector 6fc167fd
+    function validate(uint32 commission, bool blocked) external;
+
+    /// @dev Change the reward destination of the caller.
+    /// @custom:selector acf99b04
+    function setPayee(uint8 rewardDestination, address payee) external;
+
+    /// @dev Pay out the next unclaimed page of rewards of `validatorStash` for `era`.
+    /// @custom:selector 6d4fc25a
+    function payoutStakers(address validatorStash, uint32 era) external;
+
+    /// @dev Set the SCALE encoded session keys of the caller.
+    /// @custom:selector 250e0e9f
+    function setKeys(bytes memory keys, bytes memory proof) external;
+
+    /// @dev The ledger of a stash account.
+    /// @return total Total bonded balance, including unlocking chunks.
+    /// @return active Balance that is actively staked.
+    /// @return unlockingValues Values of the unlocking chunks.
+    /// @return unlockingEras Eras at which the unlocking chunks can be withdrawn.
+    /// @custom:selector fbfa941f
+    function ledger(address stash)
+        external
+        view
+        returns (
+            uint256 total,
+            uint256 active,
+            uint256[] memory unlockingValues,
+            uint32[] memory unlockingEras
+        );
+
+    /// @dev The nominations of a stash account.
+    /// @custom:selector f95baf26
+    function nominations(address stash)
+        external
+        view
+        returns (
+            address[] memory targets,
+            uint32 submittedIn,
+            bool suppressed
+        );
+
+    /// @dev The preferences of a validator stash.
+    /// @custom:selector 8f6a4692
+    function validatorPrefs(address stash)
+        external
+        view
+        returns (uint32 commission, bool blocked);
+
+    /// @dev The minimum bond required to nominate.
+    /// @custom:selector 51026cd4
+    function minNominatorBond() external view returns (uint256);
+
+    /// @dev The minimum bond required to validate.
+    /// @custom:selector c28615a3
+    function minValidatorBond() external view returns (uint256);
+
+    /// @dev The latest planned era.
+    /// @custom:selector 973628f6
+    function currentEra() external view returns (uint32);
+
+    /// @dev The era whose validator set is currently active.
+    /// @custom:selector c2f192cb
+    function activeEra() external view returns (uint32);
+
+    /// @dev The total stake backing the validators of `era`.
+    /// @custom:selector 0b1323bc
+    function erasTotalStake(uint32 era) external view returns (uint256);
+
+    /// @dev The total validator reward paid out for `era`, zero while the era is running.
+    /// @custom:selector c958a43b
+    function erasValidatorReward(uint32 era) external view returns (uint256);
+
+    /// @dev The summary of the exposure of `validator` in `era`.
+    /// @custom:selector cb2f0d77
+    function erasStakersOverview(uint32 era, address validator)
+        external
+        view
+        returns (
+            uint256 total,
+            uint256 own,
+            uint32 nominatorCount,
+            uint32 pageCount
+        );
+
+    /// @dev One page of nominators backing `validator` in `era`.
+    /// @custom:selector e6a03e59
+    function erasStakersPaged(
+        uint32 era,
+        address validator,
+        uint32 page
+    )
+        external
+        view
+        returns (
+            uint256 pageTotal,
+            address[] memory nominators,
+            uint256[] memory values
+        );
+}
@@ -0,0 +1,363 @@
+//! Precompile to interact with `pallet-staking` and `pallet-session` from the EVM.
+//!
+//! Every call is dispatched as a signed extrinsic of the caller, so the gas charged is derived
+//! from the benchmarked weight of the underlying call and refunded down to its actual weight.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use pallet_staking::{BalanceOf, MaxNominationsOf, RewardDestination, ValidatorPrefs};
+use precompile_utils::prelude::*;
+use scale_codec::Decode;
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{Dispatchable, StaticLookup},
+	Perbill,
+};
+use sp_staking::EraIndex;
+use sp_std::vec::Vec;
+
+/// Reward destinations as encoded in the Solidity interface.
+const DESTINATION_STAKED: u8 = 0;
+const DESTINATION_STASH: u8 = 1;
+const DESTINATION_ACCOUNT: u8 = 2;
+const DESTINATION_NONE: u8 = 3;
+
+/// Exposes bonding, nominating, validating and payouts to Ethereum accounts.
+pub struct StakingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> StakingPrecompile<Runtime>
+where
+	Runtime: pallet_staking::Config + pallet_session::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_staking::Call<Runtime>> + From<pallet_session::Call<Runtime>>,
+	Runtime::AccountId: Into<H160>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	/// Calls are dispatched on behalf of the caller, so a contract must not be able to run this
+	/// precompile in its own context and bond, nominate or set a payee for the account that
+	/// called it.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	#[precompile::public("bond(uint256,uint8,address)")]
+	fn bond(
+		handle: &mut impl PrecompileHandle,
+		value: U256,
+		reward_destination: u8,
+		payee: Address,
+	) -> EvmResult {
+		let value = Self::u256_to_balance(value, "value")?;
+		let payee = Self::reward_destination(reward_destination, payee)?;
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::bond { value, payee })
+	}
+
+	#[precompile::public("bondExtra(uint256)")]
+	fn bond_extra(handle: &mut impl PrecompileHandle, max_additional: U256) -> EvmResult {
+		let max_additional = Self::u256_to_balance(max_additional, "maxAdditional")?;
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::bond_extra { max_additional })
+	}
+
+	#[precompile::public("unbond(uint256)")]
+	fn unbond(handle: &mut impl PrecompileHandle, value: U256) -> EvmResult {
+		let value = Self::u256_to_balance(value, "value")?;
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::unbond { value })
+	}
+
+	#[precompile::public("withdrawUnbonded(uint32)")]
+	fn withdraw_unbonded(handle: &mut impl PrecompileHandle, num_slashing_spans: u32) -> EvmResult {
+		Self::dispatch(
+			handle,
+			pallet_staking::Call::<Runtime>::withdraw_unbonded { num_slashing_spans },
+		)
+	}
+
+	#[precompile::public("nominate(address[])")]
+	fn nominate(
+		handle: &mut impl PrecompileHandle,
+		targets: BoundedVec<Address, MaxNominationsOf<Runtime>>,
+	) -> EvmResult {
+		let targets = Vec::from(targets)
+			.into_iter()
+			.map(Self::account)
+			.map(<Runtime as frame_system::Config>::Lookup::unlookup)
+			.collect();
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::nominate { targets })
+	}
+
+	#[precompile::public("chill()")]
+	fn chill(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::chill {})
+	}
+
+	#[precompile::public("validate(uint32,bool)")]
+	fn validate(handle: &mut impl PrecompileHandle, commission: u32, blocked: bool) -> EvmResult {
+		if commission > Perbill::one().deconstruct() {
+			return Err(RevertReason::custom("Commission above 100%")
+				.in_field("commission")
+				.into());
+		}
+		let prefs = ValidatorPrefs {
+			commission: Perbill::from_parts(commission),
+			blocked,
+		};
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::validate { prefs })
+	}
+
+	#[precompile::public("setPayee(uint8,address)")]
+	fn set_payee(
+		handle: &mut impl PrecompileHandle,
+		reward_destination: u8,
+		payee: Address,
+	) -> EvmResult {
+		let payee = Self::reward_destination(reward_destination, payee)?;
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::set_payee { payee })
+	}
+
+	#[precompile::public("payoutStakers(address,uint32)")]
+	fn payout_stakers(
+		handle: &mut impl PrecompileHandle,
+		validator_stash: Address,
+		era: u32,
+	) -> EvmResult {
+		let validator_stash = Self::account(validator_stash);
+		Self::dispatch(
+			handle,
+			pallet_staking::Call::<Runtime>::payout_stakers {
+				validator_stash,
+				era,
+			},
+		)
+	}
+
+	#[precompile::public("setKeys(bytes,bytes)")]
+	fn set_keys(
+		handle: &mut impl PrecompileHandle,
+		keys: UnboundedBytes,
+		proof: UnboundedBytes,
+	) -> EvmResult {
+		let keys = Runtime::Keys::decode(&mut keys.as_bytes())
+			.map_err(|_| RevertReason::custom("Invalid session keys").in_field("keys"))?;
+		let proof = proof.into();
+		Self::dispatch(handle, pallet_session::Call::<Runtime>::set_keys { keys, proof })
+	}
+
+	#[precompile::public("ledger(address)")]
+	#[precompile::view]
+	fn ledger(
+		handle: &mut impl PrecompileHandle,
+		stash: Address,
+	) -> EvmResult<(U256, U256, Vec<U256>, Vec<u32>)> {
+		// Bonded, Ledger
+		handle.record_cost(2 * RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let ledger = pallet_staking::Bonded::<Runtime>::get(Self::account(stash))
+			.and_then(pallet_staking::Ledger::<Runtime>::get);
+		let Some(ledger) = ledger else {
+			return Ok(Default::default());
+		};
+		let (values, eras) = ledger
+			.unlocking
+			.iter()
+			.map(|chunk| (chunk.value.into(), chunk.era))
+			.unzip();
+
+		Ok((ledger.total.into(), ledger.active.into(), values, eras))
+	}
+
+	#[precompile::public("nominations(address)")]
+	#[precompile::view]
+	fn nominations(
+		handle: &mut impl PrecompileHandle,
+		stash: Address,
+	) -> EvmResult<(Vec<Address>, u32, bool)> {
+		// Nominators
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Some(nominations) = pallet_staking::Nominators::<Runtime>::get(Self::account(stash))
+		else {
+			return Ok(Default::default());
+		};
+		let targets = nominations
+			.targets
+			.into_iter()
+			.map(|target| Address(target.into()))
+			.collect();
+
+		Ok((targets, nominations.submitted_in, nominations.suppressed))
+	}
+
+	#[precompile::public("validatorPrefs(address)")]
+	#[precompile::view]
+	fn validator_prefs(handle: &mut impl PrecompileHandle, stash: Address) -> EvmResult<(u32, bool)> {
+		// Validators
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let prefs = pallet_staking::Validators::<Runtime>::get(Self::account(stash));
+
+		Ok((prefs.commission.deconstruct(), prefs.blocked))
+	}
+
+	#[precompile::public("minNominatorBond()")]
+	#[precompile::view]
+	fn min_nominator_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// MinNominatorBond
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::MinNominatorBond::<Runtime>::get().into())
+	}
+
+	#[precompile::public("minValidatorBond()")]
+	#[precompile::view]
+	fn min_validator_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// MinValidatorBond
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::MinValidatorBond::<Runtime>::get().into())
+	}
+
+	#[precompile::public("currentEra()")]
+	#[precompile::view]
+	fn current_era(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// CurrentEra
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::CurrentEra::<Runtime>::get().unwrap_or_default())
+	}
+
+	#[precompile::public("activeEra()")]
+	#[precompile::view]
+	fn active_era(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// ActiveEra
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::ActiveEra::<Runtime>::get()
+			.map(|active_era| active_era.index)
+			.unwrap_or_default())
+	}
+
+	#[precompile::public("erasTotalStake(uint32)")]
+	#[precompile::view]
+	fn eras_total_stake(handle: &mut impl PrecompileHandle, era: EraIndex) -> EvmResult<U256> {
+		// ErasTotalStake
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::ErasTotalStake::<Runtime>::get(era).into())
+	}
+
+	#[precompile::public("erasValidatorReward(uint32)")]
+	#[precompile::view]
+	fn eras_validator_reward(handle: &mut impl PrecompileHandle, era: EraIndex) -> EvmResult<U256> {
+		// ErasValidatorReward
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		Ok(pallet_staking::ErasValidatorReward::<Runtime>::get(era)
+			.map(Into::into)
+			.unwrap_or_default())
+	}
+
+	#[precompile::public("erasStakersOverview(uint32,address)")]
+	#[precompile::view]
+	fn eras_stakers_overview(
+		handle: &mut impl PrecompileHandle,
+		era: EraIndex,
+		validator: Address,
+	) -> EvmResult<(U256, U256, u32, u32)> {
+		// ErasStakersOverview
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Some(overview) =
+			pallet_staking::ErasStakersOverview::<Runtime>::get(era, Self::account(validator))
+		else {
+			return Ok(Default::default());
+		};
+
+		Ok((
+			overview.total.into(),
+			overview.own.into(),
+			overview.nominator_count,
+			overview.page_count,
+		))
+	}
+
+	#[precompile::public("erasStakersPaged(uint32,address,uint32)")]
+	#[precompile::view]
+	fn eras_stakers_paged(
+		handle: &mut impl PrecompileHandle,
+		era: EraIndex,
+		validator: Address,
+		page: u32,
+	) -> EvmResult<(U256, Vec<Address>, Vec<U256>)> {
+		// ErasStakersPaged
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let Some(exposure) =
+			pallet_staking::ErasStakersPaged::<Runtime>::get((era, Self::account(validator), page))
+		else {
+			return Ok(Default::default());
+		};
+		let (nominators, values) = exposure
+			.others
+			.into_iter()
+			.map(|individual| (Address(individual.who.into()), individual.value.into()))
+			.unzip();
+
+		Ok((exposure.page_total.into(), nominators, values))
+	}
+}
+
+impl<Runtime> StakingPrecompile<Runtime>
+where
+	Runtime: pallet_staking::Config + pallet_session::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<pallet_staking::Call<Runtime>> + From<pallet_session::Call<Runtime>>,
+	Runtime::AccountId: Into<H160>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		call: impl Into<Runtime::RuntimeCall>,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call.into())?;
+
+		Ok(())
+	}
+
+	fn account(address: Address) -> Runtime::AccountId {
+		Runtime::AddressMapping::into_account_id(address.into())
+	}
+
+	fn u256_to_balance(value: U256, field: &'static str) -> EvmResult<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field(field).into())
+	}
+
+	fn reward_destination(
+		kind: u8,
+		payee: Address,
+	) -> EvmResult<RewardDestination<Runtime::AccountId>> {
+		match kind {
+			DESTINATION_STAKED => Ok(RewardDestination::Staked),
+			DESTINATION_STASH => Ok(RewardDestination::Stash),
+			DESTINATION_ACCOUNT => Ok(RewardDestination::Account(Self::account(payee))),
+			DESTINATION_NONE => Ok(RewardDestination::None),
+			_ => Err(RevertReason::custom("Unknown reward destination")
+				.in_field("rewardDestination")
+				.into()),
+		}
+	}
+}
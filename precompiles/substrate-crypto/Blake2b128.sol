@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Blake2b128 contract's address.
+address constant BLAKE2B128_ADDRESS = 0x000000000000000000000000000000000000040e;
+
+/// @title 128 bit BLAKE2b
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 16 byte digest, as used by the `Blake2_128` storage hashers.
+/// @custom:address 0x000000000000000000000000000000000000040e
+interface Blake2b128 {}
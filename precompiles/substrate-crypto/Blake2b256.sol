@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Blake2b256 contract's address.
+address constant BLAKE2B256_ADDRESS = 0x000000000000000000000000000000000000040F;
+
+/// @title 256 bit BLAKE2b
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 32 byte digest, the hash of blocks and extrinsics.
+/// @custom:address 0x000000000000000000000000000000000000040F
+interface Blake2b256 {}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Sr25519Verify contract's address.
+address constant SR25519_VERIFY_ADDRESS = 0x000000000000000000000000000000000000040C;
+
+/// @title sr25519 signature verification
+/// @notice Takes raw input instead of ABI encoded calls. The input is the 32 byte public key, the
+/// 64 byte signature and the message, signed in the `substrate` context. The output is a 32 byte
+/// word holding 1 if the signature is valid and 0 otherwise.
+/// @custom:address 0x000000000000000000000000000000000000040C
+interface Sr25519Verify {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Twox128 contract's address.
+address constant TWOX128_ADDRESS = 0x0000000000000000000000000000000000000411;
+
+/// @title 128 bit xxHash
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 16 byte digest, which hashes pallet and storage item names in storage keys.
+/// @custom:address 0x0000000000000000000000000000000000000411
+interface Twox128 {}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Twox64 contract's address.
+address constant TWOX64_ADDRESS = 0x0000000000000000000000000000000000000410;
+
+/// @title 64 bit xxHash
+/// @notice Takes raw input instead of ABI encoded calls. The input is the data to hash and the
+/// output is its 8 byte digest, as used by the `Twox64Concat` storage hasher.
+/// @custom:address 0x0000000000000000000000000000000000000410
+interface Twox64 {}
@@ -0,0 +1,218 @@
+//! Derives the gas costs of the Substrate crypto precompiles from timings of `ECRecover`, and
+//! checks the costs in the code against them.
+//!
+//! Run with `cargo bench -p pallet-evm-precompile-substrate-crypto`. Every precompile runs on
+//! messages of the lengths in `LENGTHS`. The gas per microsecond that `ECRecover` charges is the
+//! rate at which they are charged. The bench prints the base and per word costs that this gives,
+//! and fails if a cost in the code charges less than `TOLERANCE` of that rate.
+//!
+//! The precompiles run each input in turn rather than one after the other, so that changes in the
+//! speed of the machine, frequent on shared ones, affect them all the same.
+
+use std::time::{Duration, Instant};
+
+use fp_evm::{Context, Precompile};
+use pallet_evm_precompile_simple::ECRecover;
+use pallet_evm_precompile_substrate_crypto::*;
+use pallet_evm_test_vector_support::MockHandle;
+use sp_core::{ecdsa, ed25519, keccak_256, sr25519, Pair, H160, U256};
+
+/// Precompiles in the order of the samples, after `ECRecover`.
+const PRECOMPILES: [&str; 6] = [
+	"Sr25519Verify",
+	"Ed25519Verify",
+	"Blake2b128",
+	"Blake2b256",
+	"Twox64",
+	"Twox128",
+];
+
+/// Lengths of the signed or hashed messages.
+const LENGTHS: [usize; 5] = [0, 32, 256, 1024, 4096];
+
+/// Runs of an input timed together, as hashing a short message takes less than a microsecond.
+const REPEAT: u32 = 20;
+
+/// Share of the `ECRecover` rate under which a cost fails the bench, leaving room for timing
+/// noise.
+const TOLERANCE: f64 = 0.9;
+
+/// Gas charged for an input, words of 32 bytes it is charged for, median time to run it, and
+/// median ratio of that time to the time of `ECRecover` run in the same round.
+#[derive(Clone, Copy)]
+struct Sample {
+	gas: u64,
+	words: f64,
+	micros: f64,
+	ratio: f64,
+}
+
+impl Sample {
+	/// Gas that running this input costs at the rate charged for `reference`, `ECRecover`.
+	fn reference_gas(&self, reference: &Sample) -> f64 {
+		reference.gas as f64 * self.ratio
+	}
+}
+
+/// Runs a precompile on an input and returns the gas it charged.
+type Runner = fn(&[u8]) -> u64;
+
+fn runner<P: Precompile>(input: &[u8]) -> u64 {
+	let context = Context {
+		address: H160::zero(),
+		caller: H160::zero(),
+		apparent_value: U256::zero(),
+	};
+	let mut handle = MockHandle::new(input.to_vec(), None, context);
+	P::execute(&mut handle).expect("input is valid");
+	handle.gas_used
+}
+
+/// Runs each precompile on its input in turn, at least ten times and for at least 500ms.
+///
+/// The ratios to `ECRecover` are taken within each round, so that a change in the speed of the
+/// machine between rounds does not skew them.
+fn run(cases: &[(Runner, Vec<u8>)]) -> Vec<Sample> {
+	let mut gas = vec![0; cases.len()];
+	let mut micros = vec![Vec::new(); cases.len()];
+	let mut ratios = vec![Vec::new(); cases.len()];
+	let start = Instant::now();
+	while micros[0].len() < 10 || start.elapsed() < Duration::from_millis(500) {
+		for (index, (runner, input)) in cases.iter().enumerate() {
+			let run = Instant::now();
+			for _ in 0..REPEAT {
+				gas[index] = runner(input);
+			}
+			micros[index].push(run.elapsed().as_secs_f64() * 1e6 / f64::from(REPEAT));
+		}
+		let first = *micros[0].last().expect("just pushed");
+		for (ratios, micros) in ratios.iter_mut().zip(&micros) {
+			ratios.push(micros.last().expect("just pushed") / first);
+		}
+	}
+
+	(0..cases.len())
+		.map(|index| Sample {
+			gas: gas[index],
+			words: cases[index].1.len().div_ceil(32) as f64,
+			micros: median(&mut micros[index]),
+			ratio: median(&mut ratios[index]),
+		})
+		.collect()
+}
+
+fn median(values: &mut [f64]) -> f64 {
+	values.sort_by(f64::total_cmp);
+	values[values.len() / 2]
+}
+
+/// A valid `ECRecover` input: message hash, recovery id and signature, each in 32 byte words.
+fn ecrecover_input() -> Vec<u8> {
+	let hash = keccak_256(b"ecrecover");
+	let signature = ecdsa::Pair::from_seed(&[1u8; 32]).sign_prehashed(&hash).0;
+
+	let mut input = vec![0u8; 128];
+	input[..32].copy_from_slice(&hash);
+	input[63] = signature[64] + 27;
+	input[64..].copy_from_slice(&signature[..64]);
+	input
+}
+
+/// Inputs of the precompiles of `PRECOMPILES` for a message of `length` bytes.
+fn cases(length: usize) -> [(Runner, Vec<u8>); 7] {
+	let message = vec![0x5a; length];
+	let sr25519 = sr25519::Pair::from_seed(&[2u8; 32]);
+	let ed25519 = ed25519::Pair::from_seed(&[3u8; 32]);
+
+	[
+		(runner::<ECRecover>, ecrecover_input()),
+		(
+			runner::<Sr25519Verify>,
+			[&sr25519.public().0[..], &sr25519.sign(&message).0, &message].concat(),
+		),
+		(
+			runner::<Ed25519Verify>,
+			[&ed25519.public().0[..], &ed25519.sign(&message).0, &message].concat(),
+		),
+		(runner::<Blake2b128>, message.clone()),
+		(runner::<Blake2b256>, message.clone()),
+		(runner::<Twox64>, message.clone()),
+		(runner::<Twox128>, message),
+	]
+}
+
+fn round_up(gas: f64, step: u64) -> u64 {
+	(gas / step as f64).ceil() as u64 * step
+}
+
+/// Base and per word costs charged at the `ECRecover` rate for every length: the per word cost is
+/// the slope of the least squares line, rounded up to whole gas, and the base cost covers every
+/// sample, rounded up to `step`. Words count the whole input, public key and signature included.
+fn derive(samples: &[Sample], reference: &[Sample], step: u64) -> (u64, u64) {
+	let points: Vec<(f64, f64)> = samples
+		.iter()
+		.zip(reference)
+		.map(|(sample, reference)| (sample.words, sample.reference_gas(reference)))
+		.collect();
+	let n = points.len() as f64;
+	let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+	let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+	let slope = points
+		.iter()
+		.map(|(x, y)| (x - mean_x) * (y - mean_y))
+		.sum::<f64>()
+		/ points
+			.iter()
+			.map(|(x, _)| (x - mean_x).powi(2))
+			.sum::<f64>();
+
+	let per_word = round_up(slope, 1);
+	let base = points
+		.iter()
+		.map(|(x, y)| y - x * per_word as f64)
+		.fold(0.0, f64::max);
+	(round_up(base, step), per_word)
+}
+
+fn main() {
+	// Samples of `ECRecover` and then of each precompile, for each length.
+	let mut samples: [Vec<Sample>; 7] = Default::default();
+	for length in LENGTHS {
+		for (precompile, sample) in samples.iter_mut().zip(run(&cases(length))) {
+			precompile.push(sample);
+		}
+	}
+	let (reference, samples) = samples.split_first().expect("ECRecover is sampled");
+
+	let mut undercharged = Vec::new();
+	for (precompile, samples) in PRECOMPILES.iter().zip(samples) {
+		for (length, (sample, reference)) in LENGTHS.iter().zip(samples.iter().zip(reference)) {
+			let name = format!("{precompile} ({length} bytes)");
+			let share = sample.gas as f64 / sample.reference_gas(reference);
+			println!(
+				"{name:<30} {:>6} gas {:>9.2} us {:>7.0} gas at the ECRecover rate {:>6.0}%",
+				sample.gas,
+				sample.micros,
+				sample.reference_gas(reference),
+				share * 100.0,
+			);
+			if share < TOLERANCE {
+				undercharged.push(name);
+			}
+		}
+	}
+
+	println!("Costs at the ECRecover rate:");
+	for (index, (precompile, samples)) in PRECOMPILES.iter().zip(samples).enumerate() {
+		// Signature checks cost thousands of gas and hashes tens, so they round to 100 and 10 gas.
+		let step = if index < 2 { 100 } else { 10 };
+		let (base, per_word) = derive(samples, reference, step);
+		println!("  {precompile} base {base}, per word {per_word}");
+	}
+
+	assert!(
+		undercharged.is_empty(),
+		"charged under {TOLERANCE} of the ECRecover rate: {}",
+		undercharged.join(", "),
+	);
+}
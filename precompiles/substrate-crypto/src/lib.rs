@@ -0,0 +1,187 @@
+//! Precompiles exposing the signature schemes and hashers of Substrate to the EVM.
+//!
+//! They let contracts check signatures made with session or account keys, and derive storage
+//! keys. All of them go through the runtime host functions, so results match the ones of the
+//! runtime itself.
+//!
+//! Signature verification takes the 32 byte public key, the 64 byte signature and then the
+//! message, and returns a 32 byte word holding 1 for a valid signature and 0 otherwise. Hashers
+//! take the data to hash and return the raw digest.
+//!
+//! Each cost is the highest that `benches/gas.rs` derived from the `ECRecover` gas per
+//! microsecond in six runs on an Intel Xeon at 2.0 GHz with one vCPU, with words counting the
+//! whole input. Both BLAKE2b widths take the costs of the slower one:
+//!
+//! | Precompile    | Derived                             | Cost                |
+//! |---------------|-------------------------------------|---------------------|
+//! | Sr25519Verify | 3_700 to 4_300 plus 8 to 9 a word   | 4_300 plus 9 a word |
+//! | Ed25519Verify | 3_700 to 4_200 plus 4 to 6 a word   | 4_200 plus 6 a word |
+//! | Blake2b128    | 10 to 20 plus 2 a word              | 20 plus 2 a word    |
+//! | Blake2b256    | 10 plus 2 a word                    | 20 plus 2 a word    |
+//! | Twox64        | 10 plus 1 a word                    | 10 plus 1 a word    |
+//! | Twox128       | 10 plus 1 a word                    | 10 plus 1 a word    |
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use fp_evm::{ExitError, ExitSucceed, LinearCostPrecompile, PrecompileFailure};
+use sp_core::{ed25519, sr25519};
+
+/// Splits a verification input into public key, signature and message.
+fn split_signed_message(
+	input: &[u8],
+) -> Result<([u8; 32], [u8; 64], &[u8]), PrecompileFailure> {
+	if input.len() < 96 {
+		return Err(PrecompileFailure::Error {
+			exit_status: ExitError::Other("input must contain a public key and a signature".into()),
+		});
+	}
+	let (public, input) = input.split_at(32);
+	let (signature, message) = input.split_at(64);
+
+	let mut public_bytes = [0u8; 32];
+	public_bytes.copy_from_slice(public);
+	let mut signature_bytes = [0u8; 64];
+	signature_bytes.copy_from_slice(signature);
+
+	Ok((public_bytes, signature_bytes, message))
+}
+
+fn bool_output(value: bool) -> Vec<u8> {
+	let mut output = Vec::from([0u8; 32]);
+	output[31] = value.into();
+	output
+}
+
+/// sr25519 signature verification, in the `substrate` signing context.
+pub struct Sr25519Verify;
+
+impl LinearCostPrecompile for Sr25519Verify {
+	const BASE: u64 = 4_300;
+	const WORD: u64 = 9;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		let (public, signature, message) = split_signed_message(input)?;
+
+		let valid = sp_io::crypto::sr25519_verify(
+			&sr25519::Signature::from_raw(signature),
+			message,
+			&sr25519::Public::from_raw(public),
+		);
+
+		Ok((ExitSucceed::Returned, bool_output(valid)))
+	}
+}
+
+/// ed25519 signature verification.
+pub struct Ed25519Verify;
+
+impl LinearCostPrecompile for Ed25519Verify {
+	const BASE: u64 = 4_200;
+	const WORD: u64 = 6;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		let (public, signature, message) = split_signed_message(input)?;
+
+		let valid = sp_io::crypto::ed25519_verify(
+			&ed25519::Signature::from_raw(signature),
+			message,
+			&ed25519::Public::from_raw(public),
+		);
+
+		Ok((ExitSucceed::Returned, bool_output(valid)))
+	}
+}
+
+/// 128 bit BLAKE2b, as used by the `Blake2_128` and `Blake2_128Concat` storage hashers.
+pub struct Blake2b128;
+
+impl LinearCostPrecompile for Blake2b128 {
+	const BASE: u64 = 20;
+	const WORD: u64 = 2;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		Ok((ExitSucceed::Returned, sp_io::hashing::blake2_128(input).to_vec()))
+	}
+}
+
+/// 256 bit BLAKE2b, the hasher of block and extrinsic hashes.
+pub struct Blake2b256;
+
+impl LinearCostPrecompile for Blake2b256 {
+	const BASE: u64 = 20;
+	const WORD: u64 = 2;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		Ok((ExitSucceed::Returned, sp_io::hashing::blake2_256(input).to_vec()))
+	}
+}
+
+/// 64 bit xxHash, as used by the `Twox64Concat` storage hasher.
+pub struct Twox64;
+
+impl LinearCostPrecompile for Twox64 {
+	const BASE: u64 = 10;
+	const WORD: u64 = 1;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		Ok((ExitSucceed::Returned, sp_io::hashing::twox_64(input).to_vec()))
+	}
+}
+
+/// 128 bit xxHash, which hashes pallet and storage item names in storage keys.
+pub struct Twox128;
+
+impl LinearCostPrecompile for Twox128 {
+	const BASE: u64 = 10;
+	const WORD: u64 = 1;
+
+	fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+		Ok((ExitSucceed::Returned, sp_io::hashing::twox_128(input).to_vec()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::{
+		test_precompile_failure_test_vectors, test_precompile_test_vectors,
+	};
+	use sp_core::{sr25519, Pair};
+
+	#[test]
+	fn signature_verification_matches_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Sr25519Verify>("../testdata/sr25519Verify.json")?;
+		test_precompile_failure_test_vectors::<Sr25519Verify>(
+			"../testdata/fail-sr25519Verify.json",
+		)?;
+		test_precompile_test_vectors::<Ed25519Verify>("../testdata/ed25519Verify.json")?;
+		test_precompile_failure_test_vectors::<Ed25519Verify>(
+			"../testdata/fail-ed25519Verify.json",
+		)
+	}
+
+	#[test]
+	fn hashing_matches_test_vectors() -> Result<(), String> {
+		test_precompile_test_vectors::<Blake2b128>("../testdata/blake2b128.json")?;
+		test_precompile_test_vectors::<Blake2b256>("../testdata/blake2b256.json")?;
+		test_precompile_test_vectors::<Twox64>("../testdata/twox64.json")?;
+		test_precompile_test_vectors::<Twox128>("../testdata/twox128.json")
+	}
+
+	#[test]
+	fn sr25519_verify_accepts_session_key_signatures() {
+		let babe = sr25519::Pair::from_seed(&[1u8; 32]);
+		let message = b"attestation";
+		let input = [babe.public().0.as_slice(), &babe.sign(message).0, message].concat();
+
+		let (_, output) = Sr25519Verify::execute(&input, 0).unwrap();
+		assert_eq!(output[31], 1);
+
+		let (_, output) = Sr25519Verify::execute(&input[..input.len() - 1], 0).unwrap();
+		assert_eq!(output[31], 0);
+	}
+}
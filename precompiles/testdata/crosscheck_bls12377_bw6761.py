@@ -0,0 +1,344 @@
+#!/usr/bin/env python3
+"""Cross-checks the BLS12-377 and BW6-761 test vectors without arkworks.
+
+The precompiles and the vectors both come from arkworks, so this script recomputes every
+expected output and error from the curve definitions alone: the BLS12-377 parameters of
+EIP-2539 and the BW6-761 parameters of El Housni and Guillevic, schoolbook affine arithmetic
+and a Tate pairing over the full extension field. It only needs the standard library:
+
+    python3 precompiles/testdata/crosscheck_bls12377_bw6761.py [name ...]
+
+where each name is a vector file without its extension, e.g. `bls12377Pairing`. The MSM
+vectors take a few minutes.
+"""
+
+import json
+import os
+import sys
+
+P377 = 0x01AE3A4617C510EAC63B05C06CA1493B1A22D9F300F5138F1EF3622FBA094800170B5D44300000008508C00000000001
+R377 = 0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001
+G1_377 = (
+    0x008848DEFE740A67C8FC6225BF87FF5485951E2CAA9D41BB188282C8BD37CB5CD5481512FFCD394EEAB9B16EB21BE9EF,
+    0x01914A69C5102EFF1F674F5D30AFEEC4BD7FB348CA3E52D96D182AD44FB82305C2FE3D3634A9591AFD82DE55559C8EA6,
+)
+
+P761 = 0x0122E824FB83CE0AD187C94004FAFF3EB926186A81D14688528275EF8087BE41707BA638E584E91903CEBAFF25B423048689C8ED12F9FD9071DCD3DC73EBFF2E98A116C25667A8F8160CF8AEEAF0A437E6913E6870000082F49D00000000008B
+R761 = P377
+G1_761 = (
+    0x01075B020EA190C8B277CE98A477BEAEE6A0CFB7551B27F0EE05C54B85F56FC779017FFAC15520AC11DBFCD294C2E746A17A54CE47729B905BD71FA0C9EA097103758F9A280CA27F6750DD0356133E82055928ACA6AF603F4088F3AF66E5B43D,
+    0x0058B84E0A6FC574E6FD637B45CC2A420F952589884C9EC61A7348D2A2E573A3265909F1AF7E0DBAC5B8FA1771B5B806CC685D31717A4C55BE3FB90B6FC2CDD49F9DF141B3053253B2B08119CAD0FB93AD1CB2BE0B20D2A1BAFC8F2DB4E95363,
+)
+
+
+class Field:
+    """The field F_p[w] / (w^k - nr)."""
+
+    def __init__(self, p, k, nr):
+        self.p, self.k, self.nr = p, k, nr % p
+
+    def __call__(self, *coefficients):
+        return Element(self, list(coefficients) + [0] * (self.k - len(coefficients)))
+
+
+class Element:
+    def __init__(self, field, coefficients):
+        self.field, self.c = field, coefficients
+
+    def __add__(self, other):
+        return Element(self.field, [(a + b) % self.field.p for a, b in zip(self.c, other.c)])
+
+    def __sub__(self, other):
+        return Element(self.field, [(a - b) % self.field.p for a, b in zip(self.c, other.c)])
+
+    def __neg__(self):
+        return Element(self.field, [-a % self.field.p for a in self.c])
+
+    def __eq__(self, other):
+        return self.c == other.c
+
+    def __mul__(self, other):
+        field = self.field
+        if isinstance(other, int):
+            return Element(field, [a * other % field.p for a in self.c])
+        product = [0] * (2 * field.k - 1)
+        for i, a in enumerate(self.c):
+            if a:
+                for j, b in enumerate(other.c):
+                    product[i + j] += a * b
+        for i in range(2 * field.k - 2, field.k - 1, -1):
+            product[i - field.k] += product[i] * field.nr
+        return Element(field, [a % field.p for a in product[: field.k]])
+
+    def __pow__(self, exponent):
+        result, base = self.field(1), self
+        while exponent:
+            if exponent & 1:
+                result = result * base
+            base = base * base
+            exponent >>= 1
+        return result
+
+    def is_zero(self):
+        return not any(self.c)
+
+    def inverse(self):
+        field = self.field
+        if field.k == 1:
+            return field(pow(self.c[0], -1, field.p))
+        if field.k == 2:
+            a, b = self.c
+            norm = pow((a * a - field.nr * b * b) % field.p, -1, field.p)
+            return field(a * norm % field.p, -b * norm % field.p)
+        return self ** (field.p**field.k - 2)
+
+
+INFINITY = None
+
+
+class Curve:
+    """The curve y^2 = x^3 + b over `field`."""
+
+    def __init__(self, field, b):
+        self.field, self.b = field, b
+
+    def is_on_curve(self, point):
+        x, y = point
+        return y * y == x * x * x + self.b
+
+    def slope(self, p, q):
+        if p[0] == q[0]:
+            return p[0] * p[0] * 3 * (p[1] * 2).inverse()
+        return (q[1] - p[1]) * (q[0] - p[0]).inverse()
+
+    def add(self, p, q):
+        if p is INFINITY:
+            return q
+        if q is INFINITY:
+            return p
+        if p[0] == q[0] and (p[1] + q[1]).is_zero():
+            return INFINITY
+        slope = self.slope(p, q)
+        x = slope * slope - p[0] - q[0]
+        return (x, slope * (p[0] - x) - p[1])
+
+    def mul(self, point, scalar):
+        result = INFINITY
+        for bit in bin(scalar)[2:] if scalar else "":
+            result = self.add(result, result)
+            if bit == "1":
+                result = self.add(result, point)
+        return result
+
+
+def miller_loop(curve, p, q, r):
+    """Numerator and denominator of f_{r,p}(q), for p over the base field and q over the full
+    extension field."""
+    field = q[0].field
+    lift = lambda a: field(a.c[0])
+    numerator = denominator = field(1)
+
+    def lines(t, s):
+        # The line through t and s and the vertical line through t + s, evaluated at q.
+        if t[0] == s[0] and (t[1] + s[1]).is_zero():
+            return q[0] - lift(t[0]), field(1), INFINITY
+        u = curve.add(t, s)
+        slope = lift(curve.slope(t, s))
+        return q[1] - lift(t[1]) - (q[0] - lift(t[0])) * slope, q[0] - lift(u[0]), u
+
+    t = p
+    for bit in bin(r)[3:]:
+        line, vertical, t = lines(t, t)
+        numerator, denominator = numerator * numerator * line, denominator * denominator * vertical
+        if bit == "1":
+            line, vertical, t = lines(t, p)
+            numerator, denominator = numerator * line, denominator * vertical
+    return numerator, denominator
+
+
+class Pairing:
+    def __init__(self, g1, g2, extension, untwist, r):
+        self.g1, self.g2, self.extension, self.untwist, self.r = g1, g2, extension, untwist, r
+
+    def product_is_one(self, pairs):
+        numerator = denominator = self.extension(1)
+        for p, q in pairs:
+            if p is INFINITY or q is INFINITY:
+                continue
+            n, d = miller_loop(self.g1, p, self.untwist(q), self.r)
+            numerator, denominator = numerator * n, denominator * d
+        exponent = (self.extension.p**self.extension.k - 1) // self.r
+        return numerator**exponent == denominator**exponent
+
+
+# BLS12-377: Fp2 = Fp[u] / (u^2 + 5), Fp12 = Fp[w] / (w^12 + 5) with w^6 = u, and G2 on the D-type
+# twist y^2 = x^3 + 1/u, untwisted by (x, y) -> (x w^2, y w^3).
+FP377 = Field(P377, 1, 0)
+FP2_377 = Field(P377, 2, -5)
+FP12_377 = Field(P377, 12, -5)
+BLS12_377_G1 = Curve(FP377, FP377(1))
+BLS12_377_G2 = Curve(FP2_377, FP2_377(1, 0) * FP2_377(0, 1).inverse())
+
+
+def untwist_377(q):
+    (x0, x1), (y0, y1) = q[0].c, q[1].c
+    return (FP12_377(0, 0, x0, 0, 0, 0, 0, 0, x1), FP12_377(0, 0, 0, y0, 0, 0, 0, 0, 0, y1))
+
+
+# BW6-761: Fp6 = Fp[w] / (w^6 + 4), and G2 on the M-type twist y^2 = x^3 + 4, untwisted by
+# (x, y) -> (x / w^2, y / w^3).
+FP761 = Field(P761, 1, 0)
+FP6_761 = Field(P761, 6, -4)
+BW6_761_G1 = Curve(FP761, FP761(P761 - 1))
+BW6_761_G2 = Curve(FP761, FP761(4))
+INVERSE_OF_MINUS_4 = pow(-4 % P761, -1, P761)
+
+
+def untwist_761(q):
+    x, y = q[0].c[0] * INVERSE_OF_MINUS_4, q[1].c[0] * INVERSE_OF_MINUS_4
+    return (FP6_761(0, 0, 0, 0, x % P761), FP6_761(0, 0, 0, y % P761))
+
+
+class Failure(Exception):
+    pass
+
+
+class Codec:
+    """The encoding of `ops.rs`: big-endian coordinates padded to `size` bytes."""
+
+    def __init__(self, curve, size, r, scalar_size):
+        self.curve, self.size, self.r, self.scalar_size = curve, size, r, scalar_size
+        self.point_size = 2 * curve.field.k * size
+
+    def coordinate(self, data):
+        value_size = (self.curve.field.p.bit_length() + 63) // 64 * 8
+        if any(data[: self.size - value_size]):
+            raise Failure("invalid field element top bytes")
+        value = int.from_bytes(data, "big")
+        if value >= self.curve.field.p:
+            raise Failure("field element is not canonical")
+        return value
+
+    def decode(self, data, subgroup_check):
+        k = self.curve.field.k
+        values = [self.coordinate(data[i * self.size : (i + 1) * self.size]) for i in range(2 * k)]
+        x, y = self.curve.field(*values[:k]), self.curve.field(*values[k:])
+        if x.is_zero() and y.is_zero():
+            return INFINITY
+        if not self.curve.is_on_curve((x, y)):
+            raise Failure("point is not on curve")
+        if subgroup_check and self.curve.mul((x, y), self.r) is not INFINITY:
+            raise Failure("point is not in the correct subgroup")
+        return (x, y)
+
+    def encode(self, point):
+        if point is INFINITY:
+            return bytes(self.point_size)
+        return b"".join(c.to_bytes(self.size, "big") for c in point[0].c + point[1].c)
+
+    def add(self, data):
+        if len(data) != 2 * self.point_size:
+            raise Failure("invalid input length")
+        a, b = self.decode(data[: self.point_size], False), self.decode(data[self.point_size :], False)
+        return self.encode(self.curve.add(a, b))
+
+    def msm(self, data):
+        pair_size = self.point_size + self.scalar_size
+        if not data or len(data) % pair_size:
+            raise Failure("invalid input length")
+        result = INFINITY
+        for i in range(0, len(data), pair_size):
+            point = self.decode(data[i : i + self.point_size], True)
+            scalar = int.from_bytes(data[i + self.point_size : i + pair_size], "big") % self.r
+            result = self.curve.add(result, self.curve.mul(point, scalar))
+        return self.encode(result)
+
+
+def pairing_check(pairing, g1, g2):
+    def check(data):
+        pair_size = g1.point_size + g2.point_size
+        if not data or len(data) % pair_size:
+            raise Failure("invalid input length")
+        pairs = [
+            (g1.decode(data[i : i + g1.point_size], True), g2.decode(data[i + g1.point_size : i + pair_size], True))
+            for i in range(0, len(data), pair_size)
+        ]
+        return int(pairing.product_is_one(pairs)).to_bytes(32, "big")
+
+    return check
+
+
+BLS12_377_G1_CODEC = Codec(BLS12_377_G1, 64, R377, 32)
+BLS12_377_G2_CODEC = Codec(BLS12_377_G2, 64, R377, 32)
+BW6_761_G1_CODEC = Codec(BW6_761_G1, 96, R761, 64)
+BW6_761_G2_CODEC = Codec(BW6_761_G2, 96, R761, 64)
+
+OPERATIONS = {
+    "bls12377G1Add": BLS12_377_G1_CODEC.add,
+    "bls12377G1MultiExp": BLS12_377_G1_CODEC.msm,
+    "bls12377G2Add": BLS12_377_G2_CODEC.add,
+    "bls12377G2MultiExp": BLS12_377_G2_CODEC.msm,
+    "bls12377Pairing": pairing_check(
+        Pairing(BLS12_377_G1, BLS12_377_G2, FP12_377, untwist_377, R377),
+        BLS12_377_G1_CODEC,
+        BLS12_377_G2_CODEC,
+    ),
+    "bw6761G1Add": BW6_761_G1_CODEC.add,
+    "bw6761G1MultiExp": BW6_761_G1_CODEC.msm,
+    "bw6761G2Add": BW6_761_G2_CODEC.add,
+    "bw6761G2MultiExp": BW6_761_G2_CODEC.msm,
+    "bw6761Pairing": pairing_check(
+        Pairing(BW6_761_G1, BW6_761_G2, FP6_761, untwist_761, R761),
+        BW6_761_G1_CODEC,
+        BW6_761_G2_CODEC,
+    ),
+}
+
+
+def check_generators():
+    """The G1 generators of the specifications are in the prime order subgroup and are the first
+    point of the `g+g` vectors."""
+    directory = os.path.dirname(os.path.abspath(__file__))
+    for (x, y), codec, name in [
+        (G1_377, BLS12_377_G1_CODEC, "bls12377G1Add"),
+        (G1_761, BW6_761_G1_CODEC, "bw6761G1Add"),
+    ]:
+        generator = (codec.curve.field(x), codec.curve.field(y))
+        assert codec.curve.is_on_curve(generator), name
+        assert codec.curve.mul(generator, codec.r) is INFINITY, name
+        with open(os.path.join(directory, name + ".json")) as file:
+            vector = json.load(file)[0]
+        assert bytes.fromhex(vector["Input"])[: codec.point_size] == codec.encode(generator), name
+
+
+def main(names):
+    directory = os.path.dirname(os.path.abspath(__file__))
+    check_generators()
+    failed = False
+    for name in names or OPERATIONS:
+        operation = OPERATIONS[name]
+        with open(os.path.join(directory, name + ".json")) as file:
+            for vector in json.load(file):
+                try:
+                    output = operation(bytes.fromhex(vector["Input"])).hex()
+                except Failure as error:
+                    output = f"error: {error}"
+                if output != vector["Expected"]:
+                    print(f"{name} {vector['Name']}: expected {vector['Expected']}, got {output}")
+                    failed = True
+        with open(os.path.join(directory, "fail-" + name + ".json")) as file:
+            for vector in json.load(file):
+                try:
+                    operation(bytes.fromhex(vector["Input"]))
+                    error = "success"
+                except Failure as failure:
+                    error = str(failure)
+                if error != vector["ExpectedError"]:
+                    print(f"fail-{name} {vector['Name']}: expected {vector['ExpectedError']}, got {error}")
+                    failed = True
+        print(f"{name}: checked")
+    return 1 if failed else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1:]))
@@ -10,6 +10,7 @@ use sp_runtime::{
 };
 
 use fp_account::EthereumSignature;
+use sp_std::vec::Vec;
 
 /// Type used for expressing timestamp.
 pub type Moment = u64;
@@ -51,4 +52,30 @@ pub type AccountIndex = u32;
 
 
 /// The hashing algorithm used by the chain.
-pub type Hashing = BlakeTwo256;
\ No newline at end of file
+pub type Hashing = BlakeTwo256;
+
+sp_api::decl_runtime_apis! {
+	/// Checks done off-chain before registering for fast-unstake.
+	pub trait FastUnstakeApi<AccountId> where AccountId: scale_codec::Codec {
+		/// Whether `stash` was exposed in any era still kept in the staking history. The deposit
+		/// of a stash registered for fast-unstake is slashed if it was exposed.
+		fn is_exposed(stash: AccountId) -> bool;
+	}
+
+	/// Monitoring of the automatic payout of staking rewards.
+	pub trait AutoPayoutApi<AccountId> where AccountId: scale_codec::Codec {
+		/// Eras still kept in the staking history in which `validator` has rewards left to pay
+		/// out, whether or not it enabled auto-payout.
+		fn unclaimed_eras(validator: AccountId) -> Vec<u32>;
+		/// Number of ended eras that the automatic payouts have not gone through yet.
+		fn payout_backlog() -> u32;
+	}
+
+	/// Projections of the inflation paid out to stakers.
+	pub trait InflationApi {
+		/// Yearly return of the stake of the active era, before commission, if the inflation
+		/// parameters, the stake and the total issuance stayed as they are and rewards were staked
+		/// again every era.
+		fn projected_apy() -> sp_runtime::FixedU128;
+	}
+}
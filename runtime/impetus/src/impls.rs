@@ -0,0 +1,38 @@
+//! Some configurable implementations as associated type for the substrate runtime.
+
+use frame_support::traits::{Currency, Get, Imbalance, OnUnbalanced};
+
+use crate::{Authorship, Balances, FeeSplit, Treasury};
+
+type NegativeImbalance = <Balances as Currency<crate::AccountId>>::NegativeImbalance;
+
+/// Credits the author of the current block with the given imbalance.
+pub struct Author;
+impl OnUnbalanced<NegativeImbalance> for Author {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		if let Some(author) = Authorship::author() {
+			Balances::resolve_creating(&author, amount);
+		}
+	}
+}
+
+/// Splits transaction fees between the treasury and the block author according to [`FeeSplit`].
+/// Tips, if any, go entirely to the block author.
+pub struct DealWithFees;
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			if let Some(tips) = fees_then_tips.next() {
+				<Author as OnUnbalanced<_>>::on_unbalanced(tips);
+			}
+			<Self as OnUnbalanced<_>>::on_unbalanced(fees);
+		}
+	}
+
+	fn on_nonzero_unbalanced(fees: NegativeImbalance) {
+		let (treasury, author) = FeeSplit::get();
+		let (to_treasury, to_author) = fees.ration(treasury, author);
+		<Treasury as OnUnbalanced<_>>::on_unbalanced(to_treasury);
+		<Author as OnUnbalanced<_>>::on_unbalanced(to_author);
+	}
+}
@@ -11,6 +11,10 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 /// Constant values used within the runtime.
 pub mod constants;
+mod governance;
+mod impls;
+pub mod migrations;
+mod target_bags;
 mod voter_bags;
 
 use frame_election_provider_support::{
@@ -28,18 +32,16 @@ use sp_core::{
 	OpaqueMetadata, H160, H256, U256,
 };
 use sp_runtime::{
-	create_runtime_str,
-	curve::PiecewiseLinear,
-	generic, impl_opaque_keys,
+	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		BlakeTwo256, Block as BlockT, DispatchInfoOf, Dispatchable, Get, IdentityLookup, NumberFor,
-		One, OpaqueKeys, PostDispatchInfoOf, UniqueSaturatedInto,
+		BlakeTwo256, Block as BlockT, Convert, DispatchInfoOf, Dispatchable, Get, IdentityLookup,
+		NumberFor, One, OpaqueKeys, PostDispatchInfoOf, UniqueSaturatedInto,
 	},
 	transaction_validity::{
 		TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
 	},
-	ApplyExtrinsicResult, ConsensusEngineId, ExtrinsicInclusionMode, OpaqueExtrinsic, Perbill,
-	Percent, Permill,
+	ApplyExtrinsicResult, ConsensusEngineId, ExtrinsicInclusionMode, FixedU128, OpaqueExtrinsic,
+	Perbill, Percent, Permill, Perquintill,
 };
 use sp_staking::currency_to_vote::U128CurrencyToVote;
 use sp_std::{marker::PhantomData, prelude::*};
@@ -55,12 +57,15 @@ use frame_support::{
 	genesis_builder_helper::{build_config, create_default_config},
 	parameter_types,
 	traits::{
-		ConstU32, ConstU8, FindAuthor, KeyOwnerProofSystem, LockIdentifier, OnFinalize
+		tokens::{pay::PayFromAccount, UnityAssetBalanceConversion},
+		ConstU32, ConstU8, EitherOf, EitherOfDiverse, EnsureWithSuccess, FindAuthor,
+		KeyOwnerProofSystem, LockIdentifier, OnFinalize,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_MILLIS},
 		IdentityFee, Weight,
 	},
+	PalletId,
 };
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter};
 // Frontier
@@ -85,6 +90,8 @@ use static_assertions::const_assert;
 pub use pallet_staking::StakerStatus;
 
 pub use constants::{currency::*, time::*};
+use governance::{pallet_custom_origins, StakingAdminOrigin, Treasurer};
+use impls::DealWithFees;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_system::{limits::BlockWeights, Call as SystemCall};
@@ -93,7 +100,8 @@ pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::Multiplier;
 
 mod precompiles;
-use precompiles::FrontierPrecompiles;
+pub use precompiles::FrontierPrecompiles;
+use precompiles::FrontierPrecompileAddresses;
 
 impl_opaque_keys! {
 	pub struct SessionKeys {
@@ -232,24 +240,25 @@ parameter_types! {
 	//       Attempting to do so will brick block production.
 	pub const EpochDuration: u64 = EPOCH_DURATION_IN_SLOTS;
 	pub const ExpectedBlockTime: Moment = MILLISECS_PER_BLOCK;
-	pub const ReportLongevity: u64 = 24 * 28 * 6 * EpochDuration::get();
-		// BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
+	pub const ReportLongevity: u64 =
+		BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
 	pub const MaxAuthorities: u32 = 100;
 	pub const MaxNominators: u32 = 64;
+	pub const MaxSetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
 }
 
 impl pallet_babe::Config for Runtime {
 	type EpochDuration = EpochDuration;
 	type ExpectedBlockTime = ExpectedBlockTime;
 	type EpochChangeTrigger = pallet_babe::ExternalTrigger;
-	type DisabledValidators = (); // TODO Session;
+	type DisabledValidators = Session;
 	type WeightInfo = ();
 	type MaxAuthorities = MaxAuthorities;
 	type MaxNominators = MaxNominators;
-	type KeyOwnerProof = <() as KeyOwnerProofSystem<(KeyTypeId, pallet_babe::AuthorityId)>>::Proof;
-	// TODO <Historical as KeyOwnerProofSystem<(KeyTypeId, pallet_babe::AuthorityId)>>::Proof;
-	type EquivocationReportSystem = ();
-	// TODO pallet_babe::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
+	type KeyOwnerProof =
+		<Historical as KeyOwnerProofSystem<(KeyTypeId, pallet_babe::AuthorityId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_babe::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -260,10 +269,11 @@ impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxAuthorities = ConstU32<32>;
-	type MaxNominators = ConstU32<0>;
-	type MaxSetIdSessionEntries = ();
-	type KeyOwnerProof = sp_core::Void;
-	type EquivocationReportSystem = ();
+	type MaxNominators = MaxNominators;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
+	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
 parameter_types! {
@@ -305,11 +315,13 @@ impl pallet_balances::Config for Runtime {
 
 parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::one();
+	/// Ratio in which transaction fees are split between the treasury and the block author.
+	pub const FeeSplit: (u32, u32) = (80, 20);
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
@@ -501,13 +513,13 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type SignedMaxRefunds = ConstU32<3>;
 	type SignedDepositWeight = ();
 	type SignedMaxWeight = MinerMaxWeight;
-	type SlashHandler = (); // burn slashes
+	type SlashHandler = Treasury;
 	type RewardHandler = (); // nothing to do upon rewards
 	type DataProvider = Staking;
 	type Fallback = onchain::OnChainExecution<OnChainSeqPhragmen>;
 	type GovernanceFallback = onchain::OnChainExecution<OnChainSeqPhragmen>;
 	type Solver = SequentialPhragmen<AccountId, SolutionAccuracyOf<Self>, OffchainRandomBalancing>;
-	type ForceOrigin = EnsureRoot<AccountId>;
+	type ForceOrigin = StakingAdminOrigin;
 	type MaxWinners = MaxActiveValidators;
 	type ElectionBounds = ElectionBounds;
 	type BenchmarkingConfig = ElectionProviderBenchmarkConfig;
@@ -522,6 +534,13 @@ parameter_types! {
 }
 
 type CouncilCollective = pallet_collective::Instance1;
+
+/// Root, or a super-majority of the council.
+pub type EnsureRootOrThreeFourthsCouncil = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
+>;
+
 impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type Proposal = RuntimeCall;
@@ -531,7 +550,7 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
-	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
+	type SetMembersOrigin = EnsureRootOrThreeFourthsCouncil;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
 }
 
@@ -577,6 +596,78 @@ impl pallet_elections_phragmen::Config for Runtime {
 	type WeightInfo = pallet_elections_phragmen::weights::SubstrateWeight<Runtime>;
 }
 
+// Treasury
+parameter_types! {
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 100 * IPT;
+	pub const SpendPeriod: BlockNumber = 6 * DAYS;
+	pub const Burn: Permill = Permill::zero();
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const MaxApprovals: u32 = 100;
+	pub const SpendPayoutPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxBalance: Balance = Balance::MAX;
+	/// The largest amount a council motion can spend from the treasury in one go.
+	pub const CouncilSpendLimit: Balance = 100_000 * IPT;
+	/// The largest amount a referendum on the treasurer track can spend in one go.
+	pub const TreasurerSpendLimit: Balance = 1_000_000 * IPT;
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 5>,
+	>;
+	type RejectOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = EitherOf<
+		EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>,
+		EitherOf<
+			EnsureWithSuccess<
+				pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 5>,
+				AccountId,
+				CouncilSpendLimit,
+			>,
+			EnsureWithSuccess<Treasurer, AccountId, TreasurerSpendLimit>,
+		>,
+	>;
+	type AssetKind = ();
+	type Beneficiary = AccountId;
+	type BeneficiaryLookup = IdentityLookup<AccountId>;
+	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = SpendPayoutPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TreasuryBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), AccountId> for TreasuryBenchmarkHelper {
+	fn create_asset_kind(_seed: u32) {}
+
+	fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+		AccountId::from(H160::from_slice(&seed[..20]))
+	}
+}
+
 impl pallet_evm_chain_id::Config for Runtime {}
 
 pub struct FindAuthorTruncated<F>(PhantomData<F>);
@@ -619,7 +710,7 @@ impl pallet_evm::Config for Runtime {
 	type ChainId = EVMChainId;
 	type BlockGasLimit = BlockGasLimit;
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-	type OnChargeTransaction = ();
+	type OnChargeTransaction = pallet_evm::EVMCurrencyAdapter<Balances, DealWithFees>;
 	type OnCreate = ();
 	type FindAuthor = FindAuthorTruncated<Babe>;
 	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
@@ -677,6 +768,28 @@ impl pallet_hotfix_sufficients::Config for Runtime {
 	type WeightInfo = pallet_hotfix_sufficients::weights::SubstrateWeight<Self>;
 }
 
+parameter_types! {
+	pub const RandomnessRequestDeposit: Balance = deposit(1, 128);
+}
+
+impl pallet_randomness::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Deposit = RandomnessRequestDeposit;
+	type MinBlockDelay = ConstU32<2>;
+	type MaxBlockDelay = ConstU32<{ 10 * MINUTES }>;
+	type Expiration = ConstU32<DAYS>;
+	type MaxRandomWords = ConstU8<100>;
+	type MaxTargetBlocks = ConstU32<{ 20 * MINUTES }>;
+}
+
+impl pallet_precompile_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRootOrThreeFourthsCouncil;
+	type Precompiles = FrontierPrecompileAddresses<Self>;
+	type WeightInfo = pallet_precompile_registry::weights::SubstrateWeight<Self>;
+}
+
 // voter bags
 parameter_types! {
 	pub const BagThresholds: &'static [u64] = &voter_bags::THRESHOLDS;
@@ -694,13 +807,32 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
 }
 
+// target bags
+parameter_types! {
+	pub const TargetBagThresholds: &'static [Balance] = &target_bags::THRESHOLDS;
+}
+
+type TargetBagsListInstance = pallet_bags_list::Instance2;
+
+impl pallet_bags_list::Config<TargetBagsListInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	/// Validators are scored by their approval stake, synced in idle blocks.
+	type ScoreProvider = ApprovalStake;
+	type BagThresholds = TargetBagThresholds;
+	type Score = Balance;
+	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_session::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_staking::StashOf<Self>;
 	type ShouldEndSession = Babe;
 	type NextSessionRotation = Babe;
-	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<
+		Self,
+		pallet_approval_stake::SyncGenesisTargets<Self, Staking>,
+	>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
 	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
@@ -716,7 +848,6 @@ parameter_types! {
 	pub const SessionsPerEra: sp_staking::SessionIndex = 6;
 	pub const BondingDuration: sp_staking::EraIndex = (21 * DAYS) / (EPOCH_DURATION_IN_BLOCKS * SessionsPerEra::get());
 	pub const SlashDeferDuration: sp_staking::EraIndex = 1;
-	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
 	pub const MaxExposurePageSize: u32 = 256;
 	pub const MaxControllersInDeprecationBatch: u32 = 5900;
 	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
@@ -724,15 +855,29 @@ parameter_types! {
 	pub HistoryDepth: u32 = 84;
 }
 
-pallet_staking_reward_curve::build! {
-	const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
-		min_inflation: 0_025_000,
-		max_inflation: 0_100_000,
-		ideal_stake: 0_500_000,
-		falloff: 0_050_000,
-		max_piece_count: 40,
-		test_precision: 0_005_000,
-	);
+// inflation
+parameter_types! {
+	/// The minimum and maximum inflation, ideal stake and falloff of the reward curve this
+	/// replaced, with stakers getting all of the inflation at the ideal stake. The issuance grows
+	/// by 10% a year, the treasury getting what stakers do not.
+	pub const DefaultInflationParams: pallet_inflation::InflationParams =
+		pallet_inflation::InflationParams {
+			annual_inflation: Perquintill::from_percent(10),
+			// 2.5%
+			min_inflation: Perquintill::from_parts(25_000_000_000_000_000),
+			treasury_fraction: Perquintill::zero(),
+			ideal_stake: Perquintill::from_percent(50),
+			falloff: Perquintill::from_percent(5),
+		};
+	pub const MaxAnnualInflation: Perquintill = Perquintill::from_percent(20);
+}
+
+impl pallet_inflation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = StakingAdminOrigin;
+	type DefaultParams = DefaultInflationParams;
+	type MaxAnnualInflation = MaxAnnualInflation;
+	type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
 /// Upper limit on the number of NPOS nominations.
@@ -749,38 +894,124 @@ impl pallet_staking::Config for Runtime {
 	type CurrencyBalance = Balance;
 	type UnixTime = Timestamp;
 	type CurrencyToVote = U128CurrencyToVote;
-	type RewardRemainder = (); // TODO Treasury
+	type RewardRemainder = Treasury;
 	type RuntimeEvent = RuntimeEvent;
-	type Slash = (); // TODO Treasury send the slashed funds to the treasury.
+	type Slash = Treasury; // send the slashed funds to the treasury.
 	type Reward = (); // rewards are minted from the void
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
-	/// TODO A super-majority of the council can cancel the slash.
-	// type AdminOrigin = EitherOfDiverse<
-	// 	EnsureRoot<AccountId>,
-	// 	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
-	// >;
-	type AdminOrigin = EnsureRoot<AccountId>;
+	/// A super-majority of the council, or a staking admin referendum, can cancel the slash.
+	type AdminOrigin = StakingAdminOrigin;
 	type SessionInterface = Self;
-	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type EraPayout = Inflation;
 	type NextNewSession = Session;
 	type MaxExposurePageSize = MaxExposurePageSize;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
-	type VoterList = VoterList;
+	type VoterList = pallet_approval_stake::VotersAndTargets<Runtime>;
 	type NominationsQuota = pallet_staking::FixedNominationsQuota<MAX_QUOTA_NOMINATIONS>;
-	// This a placeholder, to be introduced in the next PR as an instance of bags-list
-	type TargetList = pallet_staking::UseValidatorsMap<Self>;
+	type TargetList = TargetList;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = HistoryDepth;
-	type EventListeners = (); // NominationPools
+	type EventListeners = NominationPools;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 }
 
+// fast unstake
+parameter_types! {
+	pub const FastUnstakeDeposit: Balance = IPT;
+	pub const FastUnstakeErasToCheckPerBlock: u32 = 1;
+}
+
+// A stash registered for fast-unstake is checked against `BondingDuration + 1` eras, which must
+// not take more than an era.
+const_assert!(
+	BondingDuration::get() + 1
+		<= FastUnstakeErasToCheckPerBlock::get() * EPOCH_DURATION_IN_BLOCKS * SessionsPerEra::get()
+);
+
+impl pallet_fast_unstake::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BatchSize = ConstU32<16>;
+	type Deposit = FastUnstakeDeposit;
+	type ControlOrigin = StakingAdminOrigin;
+	type Staking = Staking;
+	type MaxErasToCheckPerBlock = ConstU32<4>;
+	type WeightInfo = pallet_fast_unstake::weights::SubstrateWeight<Runtime>;
+}
+
+// auto payout
+parameter_types! {
+	pub const AutoPayoutMaxPagesPerBlock: u32 = 4;
+}
+
+impl pallet_auto_payout::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxPayees = MaxActiveValidators;
+	type MaxPagesPerBlock = AutoPayoutMaxPagesPerBlock;
+	type WeightInfo = pallet_auto_payout::weights::SubstrateWeight<Runtime>;
+}
+
+// approval stake
+parameter_types! {
+	/// A pass over 40,000 nominators takes about an era of 600 blocks.
+	pub const ApprovalStakeMaxNominatorsPerBlock: u32 = 64;
+}
+
+impl pallet_approval_stake::Config for Runtime {
+	type Voters = VoterList;
+	type Targets = TargetList;
+	type MaxNominatorsPerBlock = ApprovalStakeMaxNominatorsPerBlock;
+	type WeightInfo = pallet_approval_stake::weights::SubstrateWeight<Runtime>;
+}
+
+// nomination pools
+parameter_types! {
+	pub const PostUnbondPoolsWindow: u32 = 4;
+	pub const NominationPoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MaxPointsToBalance: u8 = 10;
+	pub const PoolsMinJoinBond: Balance = IPT;
+	pub const PoolsMinCreateBond: Balance = 10 * IPT;
+	/// Every pool bonds as a nominator, so pools take up to this many of the electing voters.
+	pub const MaxPools: u32 = 512;
+	pub const MaxPoolMembers: u32 = 16_384;
+}
+
+pub struct BalanceToU256;
+impl Convert<Balance, U256> for BalanceToU256 {
+	fn convert(balance: Balance) -> U256 {
+		U256::from(balance)
+	}
+}
+
+pub struct U256ToBalance;
+impl Convert<U256, Balance> for U256ToBalance {
+	fn convert(n: U256) -> Balance {
+		n.try_into().unwrap_or(Balance::MAX)
+	}
+}
+
+impl pallet_nomination_pools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_nomination_pools::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RewardCounter = FixedU128;
+	type BalanceToU256 = BalanceToU256;
+	type U256ToBalance = U256ToBalance;
+	type Staking = Staking;
+	type PostUnbondingPoolsWindow = PostUnbondPoolsWindow;
+	type MaxMetadataLen = ConstU32<256>;
+	type MaxUnbonding = ConstU32<8>;
+	type PalletId = NominationPoolsPalletId;
+	type MaxPointsToBalance = MaxPointsToBalance;
+}
+
 // authorship
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
@@ -832,6 +1063,22 @@ construct_runtime!(
 		DynamicFee: pallet_dynamic_fee,
 		BaseFee: pallet_base_fee,
 		HotfixSufficients: pallet_hotfix_sufficients,
+		// Governance
+		Treasury: pallet_treasury,
+		Scheduler: pallet_scheduler,
+		Preimage: pallet_preimage,
+		Referenda: pallet_referenda,
+		ConvictionVoting: pallet_conviction_voting,
+		Origins: pallet_custom_origins::{Origin},
+		Whitelist: pallet_whitelist,
+		PrecompileRegistry: pallet_precompile_registry,
+		Randomness: pallet_randomness,
+		NominationPools: pallet_nomination_pools,
+		TargetList: pallet_bags_list::<Instance2>,
+		FastUnstake: pallet_fast_unstake,
+		AutoPayout: pallet_auto_payout,
+		Inflation: pallet_inflation,
+		ApprovalStake: pallet_approval_stake,
 	}
 );
 
@@ -892,6 +1139,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    migrations::Unreleased,
 >;
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -965,6 +1213,10 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_sudo, Sudo]
         [pallet_evm, EVM]
+        [pallet_inflation, Inflation]
+        [pallet_approval_stake, ApprovalStake]
+        [pallet_auto_payout, AutoPayout]
+        [pallet_precompile_registry, PrecompileRegistry]
     );
 }
 
@@ -1184,6 +1436,55 @@ impl_runtime_apis! {
         }
     }
 
+    impl primitives::FastUnstakeApi<Block, AccountId> for Runtime {
+        fn is_exposed(stash: AccountId) -> bool {
+            use sp_staking::StakingInterface;
+
+            // Exposures older than the history depth are pruned, so the on-chain check cannot find
+            // them either.
+            let Some(current_era) = pallet_staking::CurrentEra::<Runtime>::get() else {
+                return false;
+            };
+            let first_era = current_era.saturating_sub(BondingDuration::get().min(HistoryDepth::get()));
+            (first_era..=current_era).any(|era| Staking::is_exposed_in_era(&stash, &era))
+        }
+    }
+
+    impl primitives::AutoPayoutApi<Block, AccountId> for Runtime {
+        fn unclaimed_eras(validator: AccountId) -> Vec<sp_staking::EraIndex> {
+            AutoPayout::unclaimed_eras(&validator)
+        }
+
+        fn payout_backlog() -> u32 {
+            AutoPayout::backlog()
+        }
+    }
+
+    impl primitives::InflationApi<Block> for Runtime {
+        fn projected_apy() -> FixedU128 {
+            let total_staked = pallet_staking::ActiveEra::<Runtime>::get()
+                .map(|era| pallet_staking::ErasTotalStake::<Runtime>::get(era.index))
+                .unwrap_or_default();
+            let era_blocks = EPOCH_DURATION_IN_BLOCKS * SessionsPerEra::get();
+            let era_duration = Moment::from(era_blocks) * MILLISECS_PER_BLOCK;
+            Inflation::projected_apy(total_staked, Balances::total_issuance(), era_duration)
+        }
+    }
+
+    impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
+        fn pending_rewards(who: AccountId) -> Balance {
+            NominationPools::api_pending_rewards(who).unwrap_or_default()
+        }
+
+        fn points_to_balance(pool_id: pallet_nomination_pools::PoolId, points: Balance) -> Balance {
+            NominationPools::api_points_to_balance(pool_id, points)
+        }
+
+        fn balance_to_points(pool_id: pallet_nomination_pools::PoolId, new_funds: Balance) -> Balance {
+            NominationPools::api_balance_to_points(pool_id, new_funds)
+        }
+    }
+
     impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
         fn chain_id() -> u64 {
             <Runtime as pallet_evm::Config>::ChainId::get()
@@ -1428,7 +1729,106 @@ impl_runtime_apis! {
 
 #[cfg(test)]
 mod tests {
-    use super::{Runtime, WeightPerGas};
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_consensus_babe::{
+        digests::{CompatibleDigestItem, PreDigest, SecondaryPlainPreDigest},
+        Slot,
+    };
+    use sp_core::{ed25519, sr25519, Pair};
+    use sp_runtime::{traits::Header as _, BuildStorage};
+
+    fn pair_from_seed<P: Pair>(seed: &str) -> P {
+        P::from_string(&format!("//{}", seed), None).expect("static values are valid; qed")
+    }
+
+    fn session_keys_from_seed(seed: &str) -> SessionKeys {
+        SessionKeys {
+            babe: pair_from_seed::<sr25519::Pair>(seed).public().into(),
+            grandpa: pair_from_seed::<ed25519::Pair>(seed).public().into(),
+            im_online: pair_from_seed::<sr25519::Pair>(seed).public().into(),
+            authority_discovery: pair_from_seed::<sr25519::Pair>(seed).public().into(),
+        }
+    }
+
+    /// A single validator, elected at genesis, whose session keys are derived from `//Alith`.
+    fn validator() -> AccountId {
+        AccountId::from([1u8; 20])
+    }
+
+    /// The genesis council members.
+    fn council() -> Vec<AccountId> {
+        vec![
+            AccountId::from([2u8; 20]),
+            AccountId::from([3u8; 20]),
+            AccountId::from([4u8; 20]),
+        ]
+    }
+
+    pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+        let stash = validator();
+        let storage = RuntimeGenesisConfig {
+            balances: BalancesConfig {
+                balances: council()
+                    .into_iter()
+                    .chain([stash])
+                    .map(|account| (account, 1_000 * IPT))
+                    .collect(),
+            },
+            session: SessionConfig {
+                keys: vec![(stash, stash, session_keys_from_seed("Alith"))],
+                ..Default::default()
+            },
+            staking: StakingConfig {
+                validator_count: 1,
+                minimum_validator_count: 1,
+                stakers: vec![(stash, stash, 100 * IPT, StakerStatus::Validator)],
+                ..Default::default()
+            },
+            elections: ElectionsConfig {
+                members: council()
+                    .into_iter()
+                    .map(|member| (member, 10 * IPT))
+                    .collect(),
+            },
+            ..Default::default()
+        }
+        .build_storage()
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn offence_reported() -> bool {
+        System::events().iter().any(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::Offences(pallet_offences::Event::Offence { .. })
+            )
+        })
+    }
+
+    /// A handle running `input` in the precompile at `code_address` with DELEGATECALL, from a
+    /// contract that `caller` called.
+    fn delegatecall(
+        code_address: H160,
+        caller: AccountId,
+        input: impl Into<Vec<u8>>,
+    ) -> precompile_utils::testing::MockHandle {
+        let mut handle = precompile_utils::testing::MockHandle::new(
+            code_address,
+            fp_evm::Context {
+                address: H160::repeat_byte(0xcc),
+                caller: caller.into(),
+                apparent_value: U256::zero(),
+            },
+        );
+        handle.input = input.into();
+        handle
+    }
+
     #[test]
     fn configured_base_extrinsic_weight_is_evm_compatible() {
         let min_ethereum_transaction_weight = WeightPerGas::get() * 21_000;
@@ -1437,4 +1837,1661 @@ mod tests {
             .base_extrinsic;
         assert!(base_extrinsic.ref_time() <= min_ethereum_transaction_weight.ref_time());
     }
+
+    #[test]
+    fn transaction_fees_are_shared_with_treasury() {
+        use frame_support::traits::{Currency, OnUnbalanced};
+
+        new_test_ext().execute_with(|| {
+            let treasury_before = Balances::free_balance(TreasuryAccount::get());
+            let fees = Balances::issue(100 * IPT);
+            let tips = Balances::issue(IPT);
+
+            DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+            assert_eq!(
+                Balances::free_balance(TreasuryAccount::get()),
+                treasury_before + 80 * IPT
+            );
+        });
+    }
+
+    #[test]
+    fn babe_equivocation_is_reported_and_slashed() {
+        new_test_ext().execute_with(|| {
+            let pair = pair_from_seed::<sr25519::Pair>("Alith");
+            let slot = Slot::from(1);
+
+            // Two different headers authored by the same authority in the same slot.
+            let make_header = |state_root: H256| {
+                let pre_digest = DigestItem::babe_pre_digest(PreDigest::SecondaryPlain(
+                    SecondaryPlainPreDigest {
+                        authority_index: 0,
+                        slot,
+                    },
+                ));
+                let mut header = Header::new(
+                    1,
+                    H256::zero(),
+                    state_root,
+                    H256::zero(),
+                    generic::Digest {
+                        logs: vec![pre_digest],
+                    },
+                );
+                let seal = DigestItem::babe_seal(pair.sign(header.hash().as_ref()).into());
+                header.digest_mut().push(seal);
+                header
+            };
+            let equivocation_proof = sp_consensus_babe::EquivocationProof {
+                offender: pair.public().into(),
+                slot,
+                first_header: make_header(H256::repeat_byte(1)),
+                second_header: make_header(H256::repeat_byte(2)),
+            };
+            let key_owner_proof = Historical::prove((
+                sp_consensus_babe::KEY_TYPE,
+                sp_consensus_babe::AuthorityId::from(pair.public()),
+            ))
+            .unwrap();
+
+            assert!(Babe::report_equivocation_unsigned(
+                RuntimeOrigin::none(),
+                Box::new(equivocation_proof),
+                key_owner_proof,
+            )
+            .is_ok());
+            assert!(offence_reported());
+            assert!(pallet_staking::ValidatorSlashInEra::<Runtime>::get(0, validator()).is_some());
+        });
+    }
+
+    #[test]
+    fn grandpa_equivocation_is_reported_and_slashed() {
+        new_test_ext().execute_with(|| {
+            let pair = pair_from_seed::<ed25519::Pair>("Alith");
+            let set_id = Grandpa::current_set_id();
+            let round = 1;
+
+            // Two prevotes for different targets in the same round.
+            let signed_prevote = |target_hash: H256| {
+                let prevote = finality_grandpa::Prevote {
+                    target_hash,
+                    target_number: 1,
+                };
+                let payload = sp_consensus_grandpa::localized_payload(
+                    round,
+                    set_id,
+                    &finality_grandpa::Message::Prevote(prevote.clone()),
+                );
+                (prevote, pair.sign(&payload).into())
+            };
+            let equivocation_proof = sp_consensus_grandpa::EquivocationProof::new(
+                set_id,
+                sp_consensus_grandpa::Equivocation::Prevote(finality_grandpa::Equivocation {
+                    round_number: round,
+                    identity: pair.public().into(),
+                    first: signed_prevote(H256::repeat_byte(1)),
+                    second: signed_prevote(H256::repeat_byte(2)),
+                }),
+            );
+            let key_owner_proof = Historical::prove((
+                sp_consensus_grandpa::KEY_TYPE,
+                GrandpaId::from(pair.public()),
+            ))
+            .unwrap();
+
+            assert!(Grandpa::report_equivocation_unsigned(
+                RuntimeOrigin::none(),
+                Box::new(equivocation_proof),
+                key_owner_proof,
+            )
+            .is_ok());
+            assert!(offence_reported());
+            assert!(pallet_staking::ValidatorSlashInEra::<Runtime>::get(0, validator()).is_some());
+        });
+    }
+
+    /// Defers a slash of the genesis validator in era 0, to be cancelled by governance.
+    fn defer_slash() {
+        pallet_staking::UnappliedSlashes::<Runtime>::insert(
+            0,
+            vec![pallet_staking::UnappliedSlash {
+                validator: validator(),
+                own: 10 * IPT,
+                others: vec![],
+                reporters: vec![],
+                payout: 0,
+            }],
+        );
+    }
+
+    fn cancel_deferred_slash_call() -> RuntimeCall {
+        RuntimeCall::Staking(pallet_staking::Call::cancel_deferred_slash {
+            era: 0,
+            slash_indices: vec![0],
+        })
+    }
+
+    #[test]
+    fn council_super_majority_can_cancel_deferred_slash() {
+        use frame_support::dispatch::GetDispatchInfo;
+        use sp_runtime::traits::Hash as _;
+
+        new_test_ext().execute_with(|| {
+            defer_slash();
+
+            let members = council();
+            let threshold = members.len() as u32;
+            let proposal = cancel_deferred_slash_call();
+            let length_bound = proposal.encoded_size() as u32;
+            let proposal_weight = proposal.get_dispatch_info().weight;
+            let proposal_hash = <Runtime as frame_system::Config>::Hashing::hash_of(&proposal);
+
+            assert!(Council::propose(
+                RuntimeOrigin::signed(members[0]),
+                threshold,
+                Box::new(proposal),
+                length_bound,
+            )
+            .is_ok());
+            for member in &members[1..] {
+                assert!(
+                    Council::vote(RuntimeOrigin::signed(*member), proposal_hash, 0, true).is_ok()
+                );
+            }
+            assert!(Council::close(
+                RuntimeOrigin::signed(members[0]),
+                proposal_hash,
+                0,
+                proposal_weight,
+                length_bound,
+            )
+            .is_ok());
+
+            assert!(pallet_staking::UnappliedSlashes::<Runtime>::get(0).is_empty());
+        });
+    }
+
+    #[test]
+    fn council_minority_cannot_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            defer_slash();
+
+            let origin: RuntimeOrigin =
+                pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(2, 3).into();
+            assert!(cancel_deferred_slash_call().dispatch(origin).is_err());
+            assert_eq!(pallet_staking::UnappliedSlashes::<Runtime>::get(0).len(), 1);
+        });
+    }
+
+    #[test]
+    fn staking_admin_referendum_can_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            defer_slash();
+
+            let origin: RuntimeOrigin = pallet_custom_origins::Origin::StakingAdmin.into();
+            assert!(cancel_deferred_slash_call().dispatch(origin).is_ok());
+            assert!(pallet_staking::UnappliedSlashes::<Runtime>::get(0).is_empty());
+        });
+    }
+
+    #[test]
+    fn treasurer_referendum_cannot_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            defer_slash();
+
+            let origin: RuntimeOrigin = pallet_custom_origins::Origin::Treasurer.into();
+            assert!(cancel_deferred_slash_call().dispatch(origin).is_err());
+        });
+    }
+
+    #[test]
+    fn retiring_sudo_key_leaves_root_to_governance() {
+        let key = council()[0];
+        let mut storage = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .unwrap();
+        pallet_sudo::GenesisConfig::<Runtime> { key: Some(key) }
+            .assimilate_storage(&mut storage)
+            .unwrap();
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            let remark = || Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }));
+            assert!(Sudo::sudo(RuntimeOrigin::signed(key), remark()).is_ok());
+
+            migrations::RetireSudoKey::<Runtime>::on_runtime_upgrade();
+
+            assert!(Sudo::sudo(RuntimeOrigin::signed(key), remark()).is_err());
+        });
+    }
+
+    #[test]
+    fn target_list_follows_validators_by_approval_stake() {
+        use frame_election_provider_support::SortedListProvider;
+        use frame_support::{assert_ok, traits::OnIdle};
+        use pallet_staking::{RewardDestination, ValidatorPrefs};
+
+        new_test_ext().execute_with(|| {
+            let candidate = council()[0];
+
+            // The genesis validator is inserted before the genesis election.
+            assert_eq!(TargetList::iter().collect::<Vec<_>>(), vec![validator()]);
+
+            assert_ok!(Staking::bond(
+                RuntimeOrigin::signed(candidate),
+                200 * IPT,
+                RewardDestination::Staked,
+            ));
+            assert_ok!(Staking::validate(
+                RuntimeOrigin::signed(candidate),
+                ValidatorPrefs::default(),
+            ));
+            assert!(!TargetList::contains(&candidate));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+            assert_eq!(TargetList::iter().collect::<Vec<_>>(), vec![candidate, validator()]);
+            assert_eq!(TargetList::get_score(&candidate), Ok(200 * IPT));
+
+            assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(candidate), 50 * IPT));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+            assert_eq!(TargetList::get_score(&candidate), Ok(250 * IPT));
+
+            assert_ok!(Staking::chill(RuntimeOrigin::signed(candidate)));
+            assert!(!TargetList::contains(&candidate));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+            assert!(!TargetList::contains(&candidate));
+
+            // Validators of a chain upgraded from the validators map are migrated.
+            TargetList::unsafe_clear();
+            migrations::PopulateTargetList::<Runtime, TargetBagsListInstance>::on_runtime_upgrade();
+            assert_eq!(TargetList::iter().collect::<Vec<_>>(), vec![validator()]);
+            assert_eq!(TargetList::get_score(&validator()), Ok(100 * IPT));
+        });
+    }
+
+    #[test]
+    fn approval_stake_counts_every_nominator() {
+        use frame_election_provider_support::SortedListProvider;
+        use frame_support::{assert_ok, traits::OnIdle};
+        use pallet_staking::{RewardDestination, ValidatorPrefs};
+
+        new_test_ext().execute_with(|| {
+            let [candidate, nominator]: [AccountId; 2] = council()[..2].try_into().unwrap();
+            assert_ok!(Staking::bond(
+                RuntimeOrigin::signed(candidate),
+                200 * IPT,
+                RewardDestination::Staked,
+            ));
+            assert_ok!(Staking::validate(
+                RuntimeOrigin::signed(candidate),
+                ValidatorPrefs::default(),
+            ));
+            assert_ok!(Staking::bond(
+                RuntimeOrigin::signed(nominator),
+                50 * IPT,
+                RewardDestination::Staked,
+            ));
+            assert_ok!(Staking::nominate(
+                RuntimeOrigin::signed(nominator),
+                vec![candidate, validator()],
+            ));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+
+            // The candidate is not elected, yet counts its nominator like the validator does.
+            assert_eq!(TargetList::get_score(&candidate), Ok(250 * IPT));
+            assert_eq!(TargetList::get_score(&validator()), Ok(150 * IPT));
+
+            // Stake changes count from the next block with weight left.
+            assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(candidate), 10 * IPT));
+            assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(nominator), 10 * IPT));
+            assert_eq!(TargetList::get_score(&candidate), Ok(250 * IPT));
+            assert_eq!(TargetList::get_score(&validator()), Ok(150 * IPT));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+            assert_eq!(TargetList::get_score(&candidate), Ok(270 * IPT));
+            assert_eq!(TargetList::get_score(&validator()), Ok(160 * IPT));
+
+            assert_ok!(Staking::chill(RuntimeOrigin::signed(nominator)));
+            ApprovalStake::on_idle(System::block_number(), Weight::MAX);
+            assert_eq!(TargetList::get_score(&candidate), Ok(210 * IPT));
+            assert_eq!(TargetList::get_score(&validator()), Ok(100 * IPT));
+        });
+    }
+
+    #[test]
+    fn nominator_never_exposed_can_fast_unstake() {
+        use frame_support::{assert_ok, traits::OnIdle};
+        use pallet_staking::RewardDestination;
+        use primitives::runtime_decl_for_fast_unstake_api::FastUnstakeApiV1;
+
+        new_test_ext().execute_with(|| {
+            let nominator = council()[0];
+            type EnableFastUnstake =
+                migrations::EnableFastUnstake<Runtime, FastUnstakeErasToCheckPerBlock>;
+            EnableFastUnstake::on_runtime_upgrade();
+
+            assert_ok!(Staking::bond(
+                RuntimeOrigin::signed(nominator),
+                100 * IPT,
+                RewardDestination::Staked,
+            ));
+            assert_ok!(Staking::nominate(RuntimeOrigin::signed(nominator), vec![validator()]));
+            assert!(Runtime::is_exposed(validator()));
+            assert!(!Runtime::is_exposed(nominator));
+
+            let reserved = Balances::reserved_balance(nominator);
+            assert_ok!(FastUnstake::register_fast_unstake(RuntimeOrigin::signed(nominator)));
+            assert_eq!(
+                Balances::reserved_balance(nominator),
+                reserved + FastUnstakeDeposit::get()
+            );
+
+            // One block checks the only era so far, the next one unstakes.
+            for block in 2..4 {
+                System::set_block_number(block);
+                FastUnstake::on_idle(block, Weight::MAX);
+            }
+            assert!(pallet_staking::Bonded::<Runtime>::get(nominator).is_none());
+            assert!(!pallet_staking::Nominators::<Runtime>::contains_key(nominator));
+            assert_eq!(Balances::reserved_balance(nominator), reserved);
+        });
+    }
+
+    #[test]
+    fn pools_config_migration_only_sets_unset_values() {
+        use frame_support::assert_noop;
+        use pallet_nomination_pools::{MaxPoolMembers as PoolMembers, MaxPools as Pools};
+        use pallet_nomination_pools::{MinCreateBond, MinJoinBond};
+
+        type ConfigurePools = migrations::ConfigurePools<
+            Runtime,
+            PoolsMinJoinBond,
+            PoolsMinCreateBond,
+            MaxPools,
+            MaxPoolMembers,
+        >;
+
+        new_test_ext().execute_with(|| {
+            assert_eq!(MinJoinBond::<Runtime>::get(), 0);
+            assert_eq!(Pools::<Runtime>::get(), None);
+
+            // Governance already raised the bond to create a pool.
+            MinCreateBond::<Runtime>::put(20 * IPT);
+            ConfigurePools::on_runtime_upgrade();
+
+            assert_eq!(MinJoinBond::<Runtime>::get(), PoolsMinJoinBond::get());
+            assert_eq!(MinCreateBond::<Runtime>::get(), 20 * IPT);
+            assert_eq!(Pools::<Runtime>::get(), Some(MaxPools::get()));
+            assert_eq!(PoolMembers::<Runtime>::get(), Some(MaxPoolMembers::get()));
+
+            let depositor = council()[0];
+            assert_noop!(
+                NominationPools::create(
+                    RuntimeOrigin::signed(depositor),
+                    10 * IPT,
+                    depositor,
+                    depositor,
+                    depositor,
+                ),
+                pallet_nomination_pools::Error::<Runtime>::MinimumBondNotMet
+            );
+        });
+    }
+
+    #[test]
+    fn rewards_of_opted_in_validators_are_paid_out_when_idle() {
+        use frame_support::{
+            assert_noop, assert_ok,
+            traits::{OnFinalize, OnIdle},
+        };
+        use primitives::runtime_decl_for_auto_payout_api::AutoPayoutApiV1;
+
+        new_test_ext().execute_with(|| {
+            let stash = validator();
+            let rewarded = || {
+                System::events().iter().any(|record| {
+                    matches!(
+                        record.event,
+                        RuntimeEvent::Staking(pallet_staking::Event::Rewarded { .. })
+                    )
+                })
+            };
+
+            // The first era starts at the end of the first block, and lasts an hour.
+            Staking::on_finalize(1);
+            pallet_timestamp::Now::<Runtime>::put(Moment::from(HOURS) * MILLISECS_PER_BLOCK);
+            Staking::reward_by_ids(vec![(stash, 20)]);
+            pallet_staking::ForceEra::<Runtime>::put(pallet_staking::Forcing::ForceNew);
+            for _ in 0..2 {
+                Session::rotate_session();
+            }
+            assert_eq!(pallet_staking::ActiveEra::<Runtime>::get().unwrap().index, 1);
+            assert_eq!(Runtime::unclaimed_eras(stash), vec![0]);
+
+            AutoPayout::on_idle(2, Weight::MAX);
+            assert!(!rewarded());
+
+            assert_noop!(
+                AutoPayout::enable_auto_payout(RuntimeOrigin::signed(council()[0])),
+                pallet_auto_payout::Error::<Runtime>::NotValidator
+            );
+            assert_ok!(AutoPayout::enable_auto_payout(RuntimeOrigin::signed(stash)));
+            assert_eq!(Runtime::payout_backlog(), 1);
+
+            // Without the weight for a page, only the backfill of the new payee is checked.
+            AutoPayout::on_idle(3, RuntimeDbWeight::get().reads_writes(9, 3));
+            assert!(!rewarded());
+            assert_eq!(Runtime::unclaimed_eras(stash), vec![0]);
+
+            AutoPayout::on_idle(4, Weight::MAX);
+            assert!(rewarded());
+            assert!(Runtime::unclaimed_eras(stash).is_empty());
+            assert_eq!(Runtime::payout_backlog(), 0);
+
+            assert_noop!(
+                AutoPayout::remove_payee(RuntimeOrigin::signed(council()[0]), stash),
+                pallet_auto_payout::Error::<Runtime>::StillBonded
+            );
+            assert_ok!(AutoPayout::disable_auto_payout(RuntimeOrigin::signed(stash)));
+            assert!(!pallet_auto_payout::Payees::<Runtime>::contains_key(stash));
+        });
+    }
+
+    #[test]
+    fn era_inflation_is_split_between_stakers_and_treasury() {
+        use frame_support::{assert_noop, assert_ok};
+        use pallet_inflation::{InflationParams, MILLISECONDS_PER_YEAR};
+        use pallet_staking::EraPayout;
+        use primitives::runtime_decl_for_inflation_api::InflationApiV1;
+
+        new_test_ext().execute_with(|| {
+            let issuance: Balance = 1_000_000 * IPT;
+            let payout = |staked| Inflation::era_payout(staked, issuance, MILLISECONDS_PER_YEAR);
+
+            // Stakers get the 2.5% minimum, and half of the rest at half of the ideal stake.
+            assert_eq!(
+                payout(issuance / 4),
+                (issuance * 625 / 10_000, issuance * 375 / 10_000)
+            );
+            assert_eq!(payout(0), (issuance / 40, issuance * 3 / 40));
+
+            let params = InflationParams {
+                annual_inflation: Perquintill::from_percent(8),
+                treasury_fraction: Perquintill::from_percent(20),
+                ..DefaultInflationParams::get()
+            };
+            assert_noop!(
+                Inflation::set_params(RuntimeOrigin::signed(council()[0]), params),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Inflation::set_params(
+                    RuntimeOrigin::root(),
+                    InflationParams {
+                        annual_inflation: Perquintill::from_percent(25),
+                        ..params
+                    },
+                ),
+                pallet_inflation::Error::<Runtime>::InflationTooHigh
+            );
+            assert_ok!(Inflation::set_params(RuntimeOrigin::root(), params));
+            assert_eq!(
+                payout(issuance / 4),
+                (issuance * 42 / 1000, issuance * 38 / 1000)
+            );
+
+            // The genesis validator is the only staker.
+            assert!(Runtime::projected_apy() > FixedU128::default());
+            assert_ok!(Inflation::set_params(
+                RuntimeOrigin::root(),
+                InflationParams {
+                    treasury_fraction: Perquintill::one(),
+                    ..params
+                },
+            ));
+            assert_eq!(Runtime::projected_apy(), FixedU128::default());
+        });
+    }
+
+    // The reward curve that `DefaultInflationParams` replaced.
+    pallet_staking_reward_curve::build! {
+        const REWARD_CURVE: sp_runtime::curve::PiecewiseLinear<'static> = curve!(
+            min_inflation: 0_025_000,
+            max_inflation: 0_100_000,
+            ideal_stake: 0_500_000,
+            falloff: 0_050_000,
+            max_piece_count: 40,
+            test_precision: 0_005_000,
+        );
+    }
+
+    parameter_types! {
+        pub const RewardCurve: &'static sp_runtime::curve::PiecewiseLinear<'static> = &REWARD_CURVE;
+    }
+
+    #[test]
+    fn default_inflation_params_mint_as_much_as_the_replaced_curve() {
+        use pallet_inflation::MILLISECONDS_PER_YEAR;
+        use pallet_staking::{ConvertCurve, EraPayout};
+
+        new_test_ext().execute_with(|| {
+            let issuance: Balance = 1_000_000 * IPT;
+            // Share of the issuance a year, in thousandths of a percent.
+            let share = |payout: Balance| payout / (issuance / 100_000);
+
+            // Stake in percent: none, below, at and above the ideal stake.
+            for stake in [0, 30, 50, 70] {
+                let staked = issuance / 100 * stake;
+                let curve = ConvertCurve::<RewardCurve>::era_payout(
+                    staked,
+                    issuance,
+                    MILLISECONDS_PER_YEAR,
+                );
+                let params = Inflation::era_payout(staked, issuance, MILLISECONDS_PER_YEAR);
+
+                // Both mint 10% of the issuance, the treasury getting what stakers do not.
+                assert_eq!(params.0 + params.1, issuance / 10);
+                if stake <= 50 {
+                    assert_eq!(params, curve);
+                } else {
+                    // The curve approximates the falloff above the ideal stake with pieces.
+                    assert!(share(params.0).abs_diff(share(curve.0)) <= 5);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn ethereum_account_can_bond_and_nominate_through_precompile() {
+        use fp_evm::PrecompileFailure;
+        use pallet_evm::Precompile;
+        use pallet_evm_precompile_staking::{StakingPrecompile, StakingPrecompileCall};
+        use precompile_utils::{prelude::Address, testing::PrecompileTesterExt};
+
+        new_test_ext().execute_with(|| {
+            let nominator = council()[0];
+            let staking = H160::from_low_u64_be(2048);
+            let precompiles = PrecompilesValue::get();
+
+            precompiles
+                .prepare_test(
+                    nominator,
+                    staking,
+                    StakingPrecompileCall::<Runtime>::bond {
+                        value: (100 * IPT).into(),
+                        reward_destination: 0,
+                        payee: Address::default(),
+                    },
+                )
+                .execute_returns(());
+            precompiles
+                .prepare_test(
+                    nominator,
+                    staking,
+                    StakingPrecompileCall::<Runtime>::nominate {
+                        targets: vec![Address(validator().into())].into(),
+                    },
+                )
+                .execute_returns(());
+
+            precompiles
+                .prepare_test(
+                    nominator,
+                    staking,
+                    StakingPrecompileCall::<Runtime>::ledger {
+                        stash: Address(nominator.into()),
+                    },
+                )
+                .expect_cost(2 * RuntimeDbWeight::get().read / WeightPerGas::get().ref_time())
+                .execute_returns((
+                    U256::from(100 * IPT),
+                    U256::from(100 * IPT),
+                    Vec::<U256>::new(),
+                    Vec::<u32>::new(),
+                ));
+            assert_eq!(
+                pallet_staking::Nominators::<Runtime>::get(nominator)
+                    .map(|nominations| nominations.targets.into_inner()),
+                Some(vec![validator()]),
+            );
+
+            // A contract cannot bond more of the balance of the accounts that call it.
+            let mut handle = delegatecall(
+                staking,
+                nominator,
+                StakingPrecompileCall::<Runtime>::bond_extra {
+                    max_additional: (100 * IPT).into(),
+                },
+            );
+            assert!(matches!(
+                StakingPrecompile::<Runtime>::execute(&mut handle),
+                Err(PrecompileFailure::Revert { .. })
+            ));
+            assert_eq!(
+                pallet_staking::Ledger::<Runtime>::get(nominator).map(|ledger| ledger.total),
+                Some(100 * IPT)
+            );
+        });
+    }
+
+    #[test]
+    fn native_token_is_usable_through_erc20_precompile() {
+        use fp_evm::PrecompileFailure;
+        use pallet_evm::Precompile;
+        use pallet_evm_precompile_balances_erc20::{
+            Erc20BalancesPrecompile, Erc20BalancesPrecompileCall, SELECTOR_LOG_APPROVAL,
+            SELECTOR_LOG_TRANSFER,
+        };
+        use precompile_utils::{
+            prelude::{log3, solidity, Address, UnboundedBytes},
+            testing::PrecompileTesterExt,
+        };
+
+        type Erc20Call = Erc20BalancesPrecompileCall<Runtime, precompiles::NativeErc20Metadata>;
+
+        new_test_ext().execute_with(|| {
+            let [owner, spender, recipient]: [H160; 3] = council()
+                .into_iter()
+                .map(H160::from)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            let erc20 = H160::from_low_u64_be(2049);
+            let precompiles = PrecompilesValue::get();
+            let value = U256::from(10 * IPT);
+
+            precompiles
+                .prepare_test(owner, erc20, Erc20Call::symbol {})
+                .execute_returns(UnboundedBytes::from("IPT"));
+            precompiles
+                .prepare_test(owner, erc20, Erc20Call::decimals {})
+                .execute_returns(18u8);
+
+            precompiles
+                .prepare_test(
+                    owner,
+                    erc20,
+                    Erc20Call::approve { spender: Address(spender), value },
+                )
+                .expect_log(log3(
+                    erc20,
+                    SELECTOR_LOG_APPROVAL,
+                    owner,
+                    spender,
+                    solidity::encode_event_data(value),
+                ))
+                .execute_returns(true);
+            precompiles
+                .prepare_test(
+                    spender,
+                    erc20,
+                    Erc20Call::transfer_from {
+                        from: Address(owner),
+                        to: Address(recipient),
+                        value,
+                    },
+                )
+                .expect_log(log3(
+                    erc20,
+                    SELECTOR_LOG_TRANSFER,
+                    owner,
+                    recipient,
+                    solidity::encode_event_data(value),
+                ))
+                .execute_returns(true);
+
+            assert_eq!(Balances::free_balance(AccountId::from(owner)), 990 * IPT);
+            assert_eq!(Balances::free_balance(AccountId::from(recipient)), 1_010 * IPT);
+            precompiles
+                .prepare_test(
+                    owner,
+                    erc20,
+                    Erc20Call::allowance {
+                        owner: Address(owner),
+                        spender: Address(spender),
+                    },
+                )
+                .execute_returns(U256::zero());
+
+            // The allowance is used up.
+            precompiles
+                .prepare_test(
+                    spender,
+                    erc20,
+                    Erc20Call::transfer_from {
+                        from: Address(owner),
+                        to: Address(recipient),
+                        value,
+                    },
+                )
+                .execute_reverts(|output| output == b"ERC20: insufficient allowance");
+
+            // A contract cannot transfer the balance of the accounts that call it.
+            let mut handle = delegatecall(
+                erc20,
+                owner.into(),
+                Erc20Call::transfer {
+                    to: Address(spender),
+                    value,
+                },
+            );
+            assert!(matches!(
+                Erc20BalancesPrecompile::<Runtime, precompiles::NativeErc20Metadata>::execute(
+                    &mut handle
+                ),
+                Err(PrecompileFailure::Revert { .. })
+            ));
+            assert_eq!(Balances::free_balance(AccountId::from(owner)), 990 * IPT);
+        });
+    }
+
+    #[test]
+    fn impetus_precompiles_reject_delegatecall() {
+        use fp_evm::PrecompileFailure;
+        use pallet_evm::PrecompileSet;
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            for address in (2048..=2055).map(H160::from_low_u64_be) {
+                // Rejected before the input is decoded.
+                let mut handle = delegatecall(address, council()[0], Vec::new());
+                assert!(
+                    matches!(
+                        precompiles.execute(&mut handle),
+                        Some(Err(PrecompileFailure::Revert { .. }))
+                    ),
+                    "{address:?}"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn batch_precompile_forwards_the_caller_to_each_subcall() {
+        use pallet_evm_precompile_batch::{
+            log_subcall_failed, log_subcall_succeeded, BatchPrecompileCall,
+        };
+        use precompile_utils::{
+            evm::costs::call_cost,
+            prelude::{Address, LogExt},
+            testing::{PrecompileTesterExt, Subcall, SubcallOutput},
+        };
+
+        let caller = H160::from(council()[0]);
+        let batch = H160::from_low_u64_be(2050);
+        let [token, router, other] = [0xaa, 0xbb, 0xcc].map(H160::repeat_byte);
+
+        // Approves the router on the token, then fails the swap on the router.
+        let subcalls = move |subcall: Subcall| {
+            assert_eq!(subcall.context.caller, caller);
+            assert_eq!(subcall.context.address, subcall.address);
+            assert!(!subcall.is_static);
+            match subcall.address {
+                a if a == token => {
+                    assert_eq!(subcall.input, b"approve");
+                    assert_eq!(subcall.target_gas, Some(50_000));
+                    assert!(subcall.transfer.is_none());
+                    SubcallOutput::succeed()
+                }
+                a if a == router => {
+                    assert_eq!(subcall.context.apparent_value, U256::from(IPT));
+                    assert_eq!(subcall.transfer.map(|transfer| transfer.source), Some(caller));
+                    SubcallOutput {
+                        output: b"slippage".to_vec(),
+                        ..SubcallOutput::revert()
+                    }
+                }
+                _ => SubcallOutput::succeed(),
+            }
+        };
+        let to = vec![Address(token), Address(router), Address(other)];
+        let value = vec![U256::zero(), U256::from(IPT)];
+        let call_data = vec![b"approve".to_vec().into(), b"swap".to_vec().into()];
+        let gas_limit = vec![50_000];
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            // Each subcall pays for its log and for the call itself.
+            let config = <Runtime as pallet_evm::Config>::config();
+            let log_cost = log_subcall_failed(batch, 0).compute_cost().unwrap();
+            let subcall_cost = |value: U256| log_cost + call_cost(value, config);
+
+            precompiles
+                .prepare_test(
+                    caller,
+                    batch,
+                    BatchPrecompileCall::<Runtime>::batch_some {
+                        to: to.clone().into(),
+                        value: value.clone().into(),
+                        call_data: call_data.clone().into(),
+                        gas_limit: gas_limit.clone().into(),
+                    },
+                )
+                .with_subcall_handle(subcalls)
+                .expect_log(log_subcall_succeeded(batch, 0))
+                .expect_log(log_subcall_failed(batch, 1))
+                .expect_log(log_subcall_succeeded(batch, 2))
+                .expect_cost(2 * subcall_cost(U256::zero()) + subcall_cost(U256::from(IPT)))
+                .execute_returns(());
+
+            precompiles
+                .prepare_test(
+                    caller,
+                    batch,
+                    BatchPrecompileCall::<Runtime>::batch_some_until_failure {
+                        to: to.clone().into(),
+                        value: value.clone().into(),
+                        call_data: call_data.clone().into(),
+                        gas_limit: gas_limit.clone().into(),
+                    },
+                )
+                .with_subcall_handle(subcalls)
+                .expect_log(log_subcall_succeeded(batch, 0))
+                .expect_log(log_subcall_failed(batch, 1))
+                .expect_cost(subcall_cost(U256::zero()) + subcall_cost(U256::from(IPT)))
+                .execute_returns(());
+
+            precompiles
+                .prepare_test(
+                    caller,
+                    batch,
+                    BatchPrecompileCall::<Runtime>::batch_all {
+                        to: to.into(),
+                        value: value.into(),
+                        call_data: call_data.into(),
+                        gas_limit: gas_limit.into(),
+                    },
+                )
+                .with_subcall_handle(subcalls)
+                .execute_reverts(|output| output == b"slippage");
+        });
+    }
+
+    #[test]
+    fn call_permit_precompile_dispatches_with_the_signer_as_caller() {
+        use pallet_evm_precompile_call_permit::{CallPermitPrecompile, CallPermitPrecompileCall};
+        use precompile_utils::{
+            evm::costs::call_cost,
+            prelude::{Address, RuntimeHelper, UnboundedBytes},
+            testing::{PrecompileTesterExt, Subcall, SubcallOutput},
+        };
+        use sp_core::{ecdsa, H256};
+
+        let pair = ecdsa::Pair::from_seed(&[7u8; 32]);
+        let signer = H160::from(AccountId::from(pair.public()));
+        let relayer = H160::from(council()[0]);
+        let call_permit = H160::from_low_u64_be(2051);
+        let target = H160::repeat_byte(0xaa);
+        let (data, gas_limit, deadline) = (b"vote".to_vec(), 100_000, U256::from(1_000));
+
+        let subcalls = move |subcall: Subcall| {
+            assert_eq!(subcall.address, target);
+            assert_eq!(subcall.context.caller, signer);
+            assert_eq!(subcall.input, b"vote");
+            assert_eq!(subcall.target_gas, Some(gas_limit));
+            assert!(subcall.transfer.is_none());
+            SubcallOutput {
+                output: b"voted".to_vec(),
+                ..SubcallOutput::succeed()
+            }
+        };
+        let dispatch = |nonce: U256| {
+            let permit = CallPermitPrecompile::<Runtime>::generate_permit(
+                call_permit,
+                signer,
+                target,
+                U256::zero(),
+                &data,
+                gas_limit,
+                nonce,
+                deadline,
+            );
+            let signature = pair.sign_prehashed(&permit).0;
+            CallPermitPrecompileCall::<Runtime>::dispatch {
+                from: Address(signer),
+                to: Address(target),
+                value: U256::zero(),
+                data: data.clone().into(),
+                gas_limit,
+                deadline,
+                v: signature[64] + 27,
+                r: H256::from_slice(&signature[..32]),
+                s: H256::from_slice(&signature[32..64]),
+            }
+        };
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            pallet_timestamp::Now::<Runtime>::put(999_000);
+            // Timestamp, ChainId and Nonces, then the call itself.
+            let cost = 3_000
+                + 2 * RuntimeHelper::<Runtime>::db_read_gas_cost()
+                + RuntimeHelper::<Runtime>::db_write_gas_cost()
+                + call_cost(U256::zero(), <Runtime as pallet_evm::Config>::config());
+
+            precompiles
+                .prepare_test(relayer, call_permit, dispatch(U256::zero()))
+                .with_subcall_handle(subcalls)
+                .expect_cost(cost)
+                .execute_returns(UnboundedBytes::from(b"voted".to_vec()));
+            precompiles
+                .prepare_test(
+                    relayer,
+                    call_permit,
+                    CallPermitPrecompileCall::<Runtime>::nonces {
+                        owner: Address(signer),
+                    },
+                )
+                .execute_returns(U256::one());
+
+            // A permit can only be used once.
+            precompiles
+                .prepare_test(relayer, call_permit, dispatch(U256::zero()))
+                .with_subcall_handle(subcalls)
+                .execute_reverts(|output| output == b"Invalid permit");
+
+            pallet_timestamp::Now::<Runtime>::put(1_001_000);
+            precompiles
+                .prepare_test(relayer, call_permit, dispatch(U256::one()))
+                .with_subcall_handle(subcalls)
+                .execute_reverts(|output| output == b"Permit expired");
+        });
+    }
+
+    #[test]
+    fn precompile_registry_disables_a_precompile_without_an_upgrade() {
+        use frame_support::{assert_noop, assert_ok};
+        use pallet_evm::{IsPrecompileResult, PrecompileSet};
+        use pallet_evm_precompile_balances_erc20::Erc20BalancesPrecompileCall;
+        use pallet_evm_precompile_registry::PrecompileRegistryPrecompileCall;
+        use pallet_precompile_registry::{Error, PrecompileStatus, Statuses};
+        use precompile_utils::{prelude::Address, testing::PrecompileTesterExt};
+
+        type Erc20Call = Erc20BalancesPrecompileCall<Runtime, precompiles::NativeErc20Metadata>;
+        type RegistryCall = PrecompileRegistryPrecompileCall<Runtime>;
+
+        let caller = H160::from(council()[0]);
+        let erc20 = H160::from_low_u64_be(2049);
+        let registry = H160::from_low_u64_be(2052);
+        let council_origin = || -> RuntimeOrigin {
+            pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(3, 3).into()
+        };
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+
+            assert_noop!(
+                PrecompileRegistry::set_status(
+                    RuntimeOrigin::signed(council()[0]),
+                    erc20,
+                    PrecompileStatus::Disabled
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                PrecompileRegistry::set_status(
+                    council_origin(),
+                    H160::repeat_byte(0xaa),
+                    PrecompileStatus::Disabled
+                ),
+                Error::<Runtime>::NotAPrecompile
+            );
+
+            // Calls to a precompile pay for reading its status.
+            assert!(matches!(
+                precompiles.is_precompile(erc20, u64::MAX),
+                IsPrecompileResult::Answer {
+                    is_precompile: true,
+                    extra_cost,
+                } if extra_cost > 0
+            ));
+
+            // The Ethereum precompiles always run, without reading a status.
+            let ecrecover = H160::from_low_u64_be(1);
+            assert!(matches!(
+                precompiles.is_precompile(ecrecover, u64::MAX),
+                IsPrecompileResult::Answer {
+                    is_precompile: true,
+                    extra_cost: 0,
+                }
+            ));
+            assert_noop!(
+                PrecompileRegistry::set_status(
+                    council_origin(),
+                    ecrecover,
+                    PrecompileStatus::Disabled
+                ),
+                Error::<Runtime>::NotAPrecompile
+            );
+
+            assert_ok!(PrecompileRegistry::set_status(
+                council_origin(),
+                erc20,
+                PrecompileStatus::Disabled
+            ));
+            System::assert_last_event(
+                pallet_precompile_registry::Event::StatusChanged {
+                    address: erc20,
+                    status: PrecompileStatus::Disabled,
+                }
+                .into(),
+            );
+            precompiles
+                .prepare_test(caller, erc20, Erc20Call::total_supply {})
+                .execute_reverts(|output| output == b"Precompile is disabled");
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_precompile {
+                        address: Address(erc20),
+                    },
+                )
+                .execute_returns(true);
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_active_precompile {
+                        address: Address(erc20),
+                    },
+                )
+                .execute_returns(false);
+
+            // A deprecated precompile still runs.
+            assert_ok!(PrecompileRegistry::set_status(
+                RuntimeOrigin::root(),
+                erc20,
+                PrecompileStatus::Deprecated
+            ));
+            precompiles
+                .prepare_test(caller, erc20, Erc20Call::total_supply {})
+                .execute_some();
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_deprecated_precompile {
+                        address: Address(erc20),
+                    },
+                )
+                .execute_returns(true);
+
+            assert_ok!(PrecompileRegistry::set_status(
+                RuntimeOrigin::root(),
+                erc20,
+                PrecompileStatus::Enabled
+            ));
+            assert!(!Statuses::<Runtime>::contains_key(erc20));
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_active_precompile {
+                        address: Address(erc20),
+                    },
+                )
+                .execute_returns(true);
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_precompile {
+                        address: Address(H160::repeat_byte(0xaa)),
+                    },
+                )
+                .execute_returns(false);
+            precompiles
+                .prepare_test(
+                    caller,
+                    registry,
+                    RegistryCall::is_active_precompile {
+                        address: Address(H160::repeat_byte(0xaa)),
+                    },
+                )
+                .execute_returns(false);
+
+            // The precompiles that the registry does not manage are reported, and always active.
+            for address in [ecrecover, H160::from_low_u64_be(0x11), H160::from_low_u64_be(0x100)] {
+                precompiles
+                    .prepare_test(
+                        caller,
+                        registry,
+                        RegistryCall::is_precompile {
+                            address: Address(address),
+                        },
+                    )
+                    .execute_returns(true);
+                precompiles
+                    .prepare_test(
+                        caller,
+                        registry,
+                        RegistryCall::is_active_precompile {
+                            address: Address(address),
+                        },
+                    )
+                    .execute_returns(true);
+                precompiles
+                    .prepare_test(
+                        caller,
+                        registry,
+                        RegistryCall::is_deprecated_precompile {
+                            address: Address(address),
+                        },
+                    )
+                    .execute_returns(false);
+            }
+        });
+    }
+
+    #[test]
+    fn dispatch_precompile_only_dispatches_allowed_calls() {
+        use fp_evm::{ExitError, PrecompileFailure};
+        use frame_support::dispatch::GetDispatchInfo;
+        use pallet_evm::{GasWeightMapping, Precompile};
+        use precompile_utils::testing::PrecompileTesterExt;
+
+        let caller = council()[0];
+        let recipient = council()[1];
+        let dispatch = H160::from_low_u64_be(2053);
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            let balance = Balances::free_balance(caller);
+
+            // Charged the weight of the call, with the caller as the signed origin.
+            let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                dest: recipient,
+                value: IPT,
+            });
+            let cost = <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+                transfer.get_dispatch_info().weight,
+            );
+            precompiles
+                .prepare_test(H160::from(caller), dispatch, transfer.encode())
+                .expect_cost(cost)
+                .execute_returns(());
+            assert_eq!(Balances::free_balance(caller), balance - IPT);
+
+            let sudo = RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+                call: Box::new(RuntimeCall::System(frame_system::Call::set_code {
+                    code: vec![],
+                })),
+            });
+            let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+            let evm = RuntimeCall::EVM(pallet_evm::Call::withdraw {
+                address: H160::from(caller),
+                value: IPT,
+            });
+            let batch = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+                calls: vec![transfer, remark.clone()],
+            });
+            for call in [sudo, remark, evm, batch] {
+                precompiles
+                    .prepare_test(H160::from(caller), dispatch, call.encode())
+                    .execute_error(ExitError::Other("call not allowed".into()));
+            }
+            assert_eq!(Balances::free_balance(caller), balance - IPT);
+
+            // A contract cannot dispatch with the accounts that call it as the origin.
+            let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                dest: recipient,
+                value: IPT,
+            });
+            let mut handle = delegatecall(dispatch, caller, transfer.encode());
+            assert!(matches!(
+                precompiles::DispatchPrecompile::<Runtime>::execute(&mut handle),
+                Err(PrecompileFailure::Revert { .. })
+            ));
+            assert_eq!(Balances::free_balance(caller), balance - IPT);
+        });
+    }
+
+    #[test]
+    fn randomness_precompile_fulfils_requests_from_later_vrf_outputs() {
+        use frame_support::{storage::unhashed, traits::OnInitialize};
+        use pallet_evm_precompile_randomness::RandomnessPrecompileCall;
+        use pallet_randomness::RequestStatus;
+        use precompile_utils::testing::PrecompileTesterExt;
+
+        type RandomnessCall = RandomnessPrecompileCall<Runtime>;
+
+        fn set_babe_storage<V: Encode>(item: &[u8], value: V) {
+            unhashed::put(&frame_support::storage::storage_prefix(b"Babe", item), &value);
+        }
+
+        /// Starts block `number` after BABE has set `vrf_output` for its parent.
+        fn start_block(number: BlockNumber, vrf_output: [u8; 32]) {
+            System::set_block_number(number);
+            set_babe_storage(b"AuthorVrfRandomness", Some(vrf_output));
+            Randomness::on_initialize(number);
+        }
+
+        let requester = council()[0];
+        let purger = council()[1];
+        let randomness = H160::from_low_u64_be(2054);
+        let salt = H256::repeat_byte(0x5a);
+        let request = || RandomnessCall::request_randomness {
+            salt,
+            num_words: 2,
+            delay: 2,
+        };
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            let deposit = RandomnessRequestDeposit::get();
+            let free_balance = Balances::free_balance(requester);
+
+            // Requested in block 2, from the output of block 4.
+            start_block(2, [1; 32]);
+            precompiles
+                .prepare_test(H160::from(requester), randomness, request())
+                .execute_returns(U256::zero());
+            assert_eq!(Balances::reserved_balance(requester), deposit);
+            precompiles
+                .prepare_test(
+                    H160::from(requester),
+                    randomness,
+                    RandomnessCall::fulfil_request {
+                        request_id: U256::zero(),
+                    },
+                )
+                .execute_reverts(|output| output == b"RandomnessNotReady");
+
+            start_block(3, [2; 32]);
+            // Block 3 has no VRF output, so the one of block 2 is seen again.
+            start_block(4, [2; 32]);
+            assert_eq!(Randomness::request_status(0), RequestStatus::Pending);
+            start_block(5, [3; 32]);
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::request_status {
+                        request_id: U256::zero(),
+                    },
+                )
+                .execute_returns(2u8);
+
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::fulfil_request {
+                        request_id: U256::zero(),
+                    },
+                )
+                .execute_reverts(|output| output == b"NotRequester");
+            let words: Vec<H256> = (0..2u8)
+                .map(|index| {
+                    H256(sp_io::hashing::blake2_256(
+                        &([3u8; 32], salt, 0u64, index).encode(),
+                    ))
+                })
+                .collect();
+            precompiles
+                .prepare_test(
+                    H160::from(requester),
+                    randomness,
+                    RandomnessCall::fulfil_request {
+                        request_id: U256::zero(),
+                    },
+                )
+                .execute_returns(words);
+            assert_eq!(Balances::reserved_balance(requester), 0);
+            assert_eq!(Balances::free_balance(requester), free_balance);
+
+            // The deposit of an expired request goes to whoever purges it.
+            precompiles
+                .prepare_test(H160::from(requester), randomness, request())
+                .execute_returns(U256::one());
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::purge_expired_request {
+                        request_id: U256::one(),
+                    },
+                )
+                .execute_reverts(|output| output == b"RequestNotExpired");
+            System::set_block_number(7 + DAYS + 1);
+            let purger_balance = Balances::free_balance(purger);
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::purge_expired_request {
+                        request_id: U256::one(),
+                    },
+                )
+                .execute_returns(());
+            assert_eq!(Balances::free_balance(purger), purger_balance + deposit);
+            assert_eq!(Balances::free_balance(requester), free_balance - deposit);
+            assert_eq!(Randomness::request_status(1), RequestStatus::DoesNotExist);
+
+            // The randomness of the epoch that just ended becomes the previous one.
+            set_babe_storage(b"EpochIndex", 1u64);
+            set_babe_storage(b"Randomness", [9u8; 32]);
+            start_block(7 + DAYS + 2, [4; 32]);
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::current_epoch_randomness {},
+                )
+                .execute_returns(H256::repeat_byte(9));
+            precompiles
+                .prepare_test(
+                    H160::from(purger),
+                    randomness,
+                    RandomnessCall::previous_epoch_randomness {},
+                )
+                .execute_returns(H256::zero());
+        });
+    }
+
+    #[test]
+    fn ethereum_account_can_join_a_pool_and_claim_through_precompile() {
+        use fp_evm::PrecompileFailure;
+        use frame_support::assert_ok;
+        use pallet_evm::Precompile;
+        use pallet_evm_precompile_nomination_pools::{
+            NominationPoolsPrecompile, NominationPoolsPrecompileCall,
+        };
+        use precompile_utils::{prelude::Address, testing::PrecompileTesterExt};
+
+        type PoolsCall = NominationPoolsPrecompileCall<Runtime>;
+
+        new_test_ext().execute_with(|| {
+            let [depositor, member]: [AccountId; 2] = council()[..2].try_into().unwrap();
+            let pools = H160::from_low_u64_be(2055);
+            let precompiles = PrecompilesValue::get();
+
+            assert_ok!(NominationPools::create(
+                RuntimeOrigin::signed(depositor),
+                10 * IPT,
+                depositor,
+                depositor,
+                depositor,
+            ));
+            precompiles
+                .prepare_test(
+                    member,
+                    pools,
+                    PoolsCall::join {
+                        amount: (5 * IPT).into(),
+                        pool_id: 1,
+                    },
+                )
+                .execute_returns(());
+
+            precompiles
+                .prepare_test(
+                    member,
+                    pools,
+                    PoolsCall::member {
+                        member: Address(member.into()),
+                    },
+                )
+                .expect_cost(RuntimeDbWeight::get().read / WeightPerGas::get().ref_time())
+                .execute_returns((1u32, U256::from(5 * IPT), U256::zero()));
+            precompiles
+                .prepare_test(member, pools, PoolsCall::pool { pool_id: 1 })
+                .execute_returns((1u8, U256::from(15 * IPT), 2u32));
+            precompiles
+                .prepare_test(member, pools, PoolsCall::pool { pool_id: 2 })
+                .execute_returns((0u8, U256::zero(), 0u32));
+            precompiles
+                .prepare_test(
+                    member,
+                    pools,
+                    PoolsCall::points_to_balance {
+                        pool_id: 1,
+                        points: (5 * IPT).into(),
+                    },
+                )
+                .execute_returns(U256::from(5 * IPT));
+
+            // Rewards are shared by points: the member holds a third of them.
+            assert_ok!(Balances::transfer_allow_death(
+                RuntimeOrigin::signed(depositor),
+                NominationPools::create_reward_account(1),
+                3 * IPT,
+            ));
+            precompiles
+                .prepare_test(
+                    member,
+                    pools,
+                    PoolsCall::pending_rewards {
+                        member: Address(member.into()),
+                    },
+                )
+                .execute_returns(U256::from(IPT));
+            let free_balance = Balances::free_balance(member);
+            precompiles
+                .prepare_test(member, pools, PoolsCall::claim_payout {})
+                .execute_returns(());
+            assert_eq!(Balances::free_balance(member), free_balance + IPT);
+            assert_eq!(NominationPools::api_pending_rewards(member), Some(0));
+
+            // A contract cannot bond more of the balance of the accounts that call it.
+            let mut handle =
+                delegatecall(pools, member, PoolsCall::bond_extra { amount: IPT.into() });
+            assert!(matches!(
+                NominationPoolsPrecompile::<Runtime>::execute(&mut handle),
+                Err(PrecompileFailure::Revert { .. })
+            ));
+            assert_eq!(
+                pallet_nomination_pools::PoolMembers::<Runtime>::get(member)
+                    .map(|member| member.points),
+                Some(5 * IPT)
+            );
+        });
+    }
+
+    #[test]
+    fn precompile_interfaces_match_the_dispatch_code() {
+        use pallet_evm_precompile_balances_erc20::{
+            Erc20BalancesPrecompileCall, SELECTOR_LOG_APPROVAL, SELECTOR_LOG_TRANSFER,
+        };
+        use pallet_evm_precompile_batch::{
+            BatchPrecompileCall, LOG_SUBCALL_FAILED, LOG_SUBCALL_SUCCEEDED,
+        };
+        use pallet_evm_precompile_call_permit::CallPermitPrecompileCall;
+        use pallet_evm_precompile_nomination_pools::NominationPoolsPrecompileCall;
+        use pallet_evm_precompile_randomness::RandomnessPrecompileCall;
+        use pallet_evm_precompile_registry::PrecompileRegistryPrecompileCall;
+        use pallet_evm_precompile_staking::StakingPrecompileCall;
+        use std::collections::BTreeSet;
+
+        type Erc20Call = Erc20BalancesPrecompileCall<Runtime, precompiles::NativeErc20Metadata>;
+
+        // Selectors and log topics of the precompiles that decode ABI encoded calls. The others
+        // take raw input, so their interfaces must not declare any function.
+        let dispatched: Vec<(u64, &[u32], Vec<[u8; 32]>)> = vec![
+            (2048, StakingPrecompileCall::<Runtime>::selectors(), vec![]),
+            (
+                2049,
+                Erc20Call::selectors(),
+                vec![SELECTOR_LOG_TRANSFER, SELECTOR_LOG_APPROVAL],
+            ),
+            (
+                2050,
+                BatchPrecompileCall::<Runtime>::selectors(),
+                vec![LOG_SUBCALL_SUCCEEDED, LOG_SUBCALL_FAILED],
+            ),
+            (2051, CallPermitPrecompileCall::<Runtime>::selectors(), vec![]),
+            (2052, PrecompileRegistryPrecompileCall::<Runtime>::selectors(), vec![]),
+            (2054, RandomnessPrecompileCall::<Runtime>::selectors(), vec![]),
+            (2055, NominationPoolsPrecompileCall::<Runtime>::selectors(), vec![]),
+        ];
+
+        let interfaces = precompile_interfaces::interfaces().unwrap();
+        let addresses = FrontierPrecompiles::<Runtime>::used_addresses();
+        assert_eq!(interfaces.len(), addresses.len());
+
+        for address in addresses {
+            let declared: Vec<_> = interfaces
+                .iter()
+                .filter(|interface| interface.address == address)
+                .collect();
+            assert_eq!(declared.len(), 1, "{address:?} has one interface");
+            let interface = declared[0];
+
+            for function in &interface.functions {
+                assert_eq!(
+                    function.documented_selector,
+                    Some(function.selector()),
+                    "{}.{}",
+                    interface.name,
+                    function.signature()
+                );
+            }
+            for event in &interface.events {
+                assert_eq!(
+                    event.documented_selector,
+                    Some(event.selector()),
+                    "{}.{}",
+                    interface.name,
+                    event.signature()
+                );
+            }
+
+            let (selectors, topics): (BTreeSet<u32>, BTreeSet<[u8; 32]>) = dispatched
+                .iter()
+                .find(|(precompile, ..)| H160::from_low_u64_be(*precompile) == address)
+                .map(|(_, selectors, topics)| {
+                    (
+                        selectors.iter().copied().collect(),
+                        topics.iter().copied().collect(),
+                    )
+                })
+                .unwrap_or_default();
+            assert_eq!(
+                interface
+                    .functions
+                    .iter()
+                    .map(|function| function.selector())
+                    .collect::<BTreeSet<_>>(),
+                selectors,
+                "functions of {}",
+                interface.name
+            );
+            assert_eq!(
+                interface
+                    .events
+                    .iter()
+                    .map(|event| event.selector())
+                    .collect::<BTreeSet<_>>(),
+                topics,
+                "events of {}",
+                interface.name
+            );
+        }
+    }
+
+    #[test]
+    fn bn128_precompiles_match_test_vectors() -> Result<(), String> {
+        use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+        use pallet_evm_test_vector_support::{
+            test_precompile_failure_test_vectors, test_precompile_test_vectors,
+        };
+
+        for address in 6..=8 {
+            assert!(FrontierPrecompiles::<Runtime>::used_addresses()
+                .contains(&H160::from_low_u64_be(address)));
+        }
+        test_precompile_test_vectors::<Bn128Add>("../../precompiles/testdata/bn256Add.json")?;
+        test_precompile_test_vectors::<Bn128Mul>("../../precompiles/testdata/bn256ScalarMul.json")?;
+        test_precompile_test_vectors::<Bn128Pairing>("../../precompiles/testdata/bn256Pairing.json")?;
+        test_precompile_failure_test_vectors::<Bn128Add>("../../precompiles/testdata/fail-bn256Add.json")?;
+        test_precompile_failure_test_vectors::<Bn128Mul>(
+            "../../precompiles/testdata/fail-bn256ScalarMul.json",
+        )?;
+        test_precompile_failure_test_vectors::<Bn128Pairing>(
+            "../../precompiles/testdata/fail-bn256Pairing.json",
+        )
+    }
+
+    #[test]
+    fn blake2f_precompile_matches_eip152_test_vectors() -> Result<(), String> {
+        use pallet_evm_precompile_blake2::Blake2F;
+        use pallet_evm_test_vector_support::{
+            test_precompile_failure_test_vectors, test_precompile_test_vectors,
+        };
+
+        assert!(FrontierPrecompiles::<Runtime>::used_addresses()
+            .contains(&H160::from_low_u64_be(9)));
+        test_precompile_test_vectors::<Blake2F>("../../precompiles/testdata/blake2F.json")?;
+        test_precompile_failure_test_vectors::<Blake2F>("../../precompiles/testdata/fail-blake2f.json")
+    }
+
+    #[test]
+    fn precompiles_run_their_test_vectors_at_their_address() {
+        use pallet_evm::PrecompileSet;
+
+        let table = [
+            (0x06, "bn256Add"),
+            (0x07, "bn256ScalarMul"),
+            (0x08, "bn256Pairing"),
+            (0x09, "blake2F"),
+            (0x0a, "kzgPointEvaluation"),
+            (0x0b, "blsG1Add"),
+            (0x0c, "blsG1MultiExp"),
+            (0x0d, "blsG2Add"),
+            (0x0e, "blsG2MultiExp"),
+            (0x0f, "blsPairing"),
+            (0x10, "blsMapG1"),
+            (0x11, "blsMapG2"),
+            (0x100, "p256Verify"),
+            (1026, "bls12377G1Add"),
+            (1027, "bls12377G1MultiExp"),
+            (1028, "bls12377G2Add"),
+            (1029, "bls12377G2MultiExp"),
+            (1030, "bls12377Pairing"),
+            (1031, "bw6761G1Add"),
+            (1032, "bw6761G1MultiExp"),
+            (1033, "bw6761G2Add"),
+            (1034, "bw6761G2MultiExp"),
+            (1035, "bw6761Pairing"),
+            (1036, "sr25519Verify"),
+            (1037, "ed25519Verify"),
+            (1038, "blake2b128"),
+            (1039, "blake2b256"),
+            (1040, "twox64"),
+            (1041, "twox128"),
+            (1042, "poseidon"),
+        ];
+
+        new_test_ext().execute_with(|| {
+            let precompiles = PrecompilesValue::get();
+            for (address, file) in table {
+                let address = H160::from_low_u64_be(address);
+                let path = format!("../../precompiles/testdata/{file}.json");
+                let vectors: serde_json::Value =
+                    serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+                // The first vector of each file is a valid input.
+                let vector = &vectors[0];
+                let mut handle = precompile_utils::testing::MockHandle::new(
+                    address,
+                    fp_evm::Context {
+                        address,
+                        caller: H160::repeat_byte(0xcc),
+                        apparent_value: U256::zero(),
+                    },
+                );
+                handle.input = hex::decode(vector["Input"].as_str().unwrap()).unwrap();
+
+                let output = precompiles
+                    .execute(&mut handle)
+                    .unwrap_or_else(|| panic!("no precompile at {address:?}"))
+                    .unwrap_or_else(|error| panic!("{file} at {address:?}: {error:?}"))
+                    .output;
+                assert_eq!(
+                    hex::encode(output),
+                    vector["Expected"].as_str().unwrap(),
+                    "{} at {address:?}",
+                    vector["Name"]
+                );
+            }
+        });
+    }
 }
@@ -0,0 +1,140 @@
+//! Storage migrations for the impetus runtime.
+
+use frame_election_provider_support::{ScoreProvider, SortedListProvider};
+use frame_support::{
+	storage::migration::take_storage_value,
+	traits::{Get, OnRuntimeUpgrade, PalletInfoAccess},
+	weights::Weight,
+};
+use sp_runtime::traits::Zero;
+use sp_std::{boxed::Box, marker::PhantomData};
+
+/// Migrations to apply on the next runtime upgrade.
+pub type Unreleased = (
+	PopulateTargetList<crate::Runtime, pallet_bags_list::Instance2>,
+	EnableFastUnstake<crate::Runtime, crate::FastUnstakeErasToCheckPerBlock>,
+	ConfigurePools<
+		crate::Runtime,
+		crate::PoolsMinJoinBond,
+		crate::PoolsMinCreateBond,
+		crate::MaxPools,
+		crate::MaxPoolMembers,
+	>,
+);
+
+/// Removes the sudo key, leaving root reachable only through governance.
+///
+/// Retiring sudo is done in two runtime upgrades:
+///
+/// 1. Once referenda have been exercised on every track and the council is seated, add
+///    `RetireSudoKey<Runtime>` to [`Unreleased`]. After the upgrade, `Sudo::sudo` and friends
+///    fail with `RequireSudo` for every caller, while root calls go through the `root` and
+///    `whitelisted_caller` referendum tracks.
+/// 2. In a following release, remove `RetireSudoKey` from [`Unreleased`], drop `Sudo` from
+///    `construct_runtime!` and the `sudo` entry from the chain spec. Pallet indices are left
+///    untouched as the pallet is not the last one in the runtime.
+///
+/// The migration is idempotent: running it on a chain without a sudo key is a no-op.
+pub struct RetireSudoKey<T>(PhantomData<T>);
+
+impl<T: pallet_sudo::Config> OnRuntimeUpgrade for RetireSudoKey<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let pallet = <pallet_sudo::Pallet<T> as PalletInfoAccess>::name();
+		match take_storage_value::<T::AccountId>(pallet.as_bytes(), b"Key", &[]) {
+			Some(_) => T::DbWeight::get().reads_writes(1, 1),
+			None => T::DbWeight::get().reads(1),
+		}
+	}
+}
+
+/// Inserts the existing validators into the bags-list instance `I` that staking now uses as its
+/// target list, scored by the score provider of the list. The stake of their nominators is
+/// counted by the approval stake pallet as its pass over all nominators reaches them.
+///
+/// The target list used to be the `Validators` map of staking itself. Validators added after the
+/// upgrade are inserted as they start validating, so the migration is a no-op once the list holds
+/// any account.
+pub struct PopulateTargetList<T, I>(PhantomData<(T, I)>);
+
+impl<T, I> OnRuntimeUpgrade for PopulateTargetList<T, I>
+where
+	T: pallet_staking::Config + pallet_bags_list::Config<I>,
+	I: 'static,
+{
+	fn on_runtime_upgrade() -> Weight {
+		type TargetList<T, I> = pallet_bags_list::Pallet<T, I>;
+
+		if TargetList::<T, I>::count() > 0 {
+			return T::DbWeight::get().reads(1);
+		}
+		let inserted = TargetList::<T, I>::unsafe_regenerate(
+			pallet_staking::Validators::<T>::iter_keys(),
+			Box::new(|who| <T as pallet_bags_list::Config<I>>::ScoreProvider::score(who)),
+		);
+
+		// Per validator: its key and score, then the bag and the node it is inserted in.
+		let inserted = u64::from(inserted);
+		T::DbWeight::get().reads_writes(1 + 6 * inserted, 3 * inserted)
+	}
+}
+
+/// Starts checking the stashes registered for fast-unstake, `ErasToCheckPerBlock` eras per block.
+///
+/// The pallet checks nothing until its control origin sets how many eras to check per block. A
+/// value already set by governance is left untouched.
+pub struct EnableFastUnstake<T, ErasToCheckPerBlock>(PhantomData<(T, ErasToCheckPerBlock)>);
+
+impl<T, ErasToCheckPerBlock> OnRuntimeUpgrade for EnableFastUnstake<T, ErasToCheckPerBlock>
+where
+	T: pallet_fast_unstake::Config,
+	ErasToCheckPerBlock: Get<u32>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		if pallet_fast_unstake::ErasToCheckPerBlock::<T>::get() != 0 {
+			return T::DbWeight::get().reads(1);
+		}
+		pallet_fast_unstake::ErasToCheckPerBlock::<T>::put(ErasToCheckPerBlock::get());
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+/// Sets the bonds to join and create a nomination pool, and the maximum number of pools and of
+/// pool members.
+///
+/// Chains that added the pallet in a runtime upgrade have none of them set, so pools can be
+/// created and joined with no bond, and in any number. Values already set by governance are left
+/// untouched.
+pub struct ConfigurePools<T, MinJoinBond, MinCreateBond, MaxPools, MaxPoolMembers>(
+	PhantomData<(T, MinJoinBond, MinCreateBond, MaxPools, MaxPoolMembers)>,
+);
+
+impl<T, MinJoinBond, MinCreateBond, MaxPools, MaxPoolMembers> OnRuntimeUpgrade
+	for ConfigurePools<T, MinJoinBond, MinCreateBond, MaxPools, MaxPoolMembers>
+where
+	T: pallet_nomination_pools::Config,
+	MinJoinBond: Get<pallet_nomination_pools::BalanceOf<T>>,
+	MinCreateBond: Get<pallet_nomination_pools::BalanceOf<T>>,
+	MaxPools: Get<u32>,
+	MaxPoolMembers: Get<u32>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut writes = 0;
+		if pallet_nomination_pools::MinJoinBond::<T>::get().is_zero() {
+			pallet_nomination_pools::MinJoinBond::<T>::put(MinJoinBond::get());
+			writes += 1;
+		}
+		if pallet_nomination_pools::MinCreateBond::<T>::get().is_zero() {
+			pallet_nomination_pools::MinCreateBond::<T>::put(MinCreateBond::get());
+			writes += 1;
+		}
+		if pallet_nomination_pools::MaxPools::<T>::get().is_none() {
+			pallet_nomination_pools::MaxPools::<T>::put(MaxPools::get());
+			writes += 1;
+		}
+		if pallet_nomination_pools::MaxPoolMembers::<T>::get().is_none() {
+			pallet_nomination_pools::MaxPoolMembers::<T>::put(MaxPoolMembers::get());
+			writes += 1;
+		}
+		T::DbWeight::get().reads_writes(4, writes)
+	}
+}
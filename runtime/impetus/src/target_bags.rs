@@ -0,0 +1,219 @@
+//! Bag thresholds of the target list.
+//!
+//! Validators are scored by approval stake, a balance, so the thresholds span the range of
+//! `Balance` rather than `VoteWeight`. They follow the geometric progression of the voter bags,
+//! starting from the minimum validator bond of 100 IPT.
+
+/// Existential score for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const EXISTENTIAL_SCORE: u128 = 100_000_000_000_000_000_000;
+
+/// Constant ratio between bags for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const CONSTANT_RATIO: f64 = 1.2391526823802552;
+
+/// Upper thresholds delimiting the bag list.
+pub const THRESHOLDS: [u128; 200] = [
+    100_000_000_000_000_000_000,
+    123_915_268_238_025_523_200,
+    153_549_937_025_018_167_296,
+    190_271_816_343_870_537_728,
+    235_775_831_603_870_466_048,
+    292_162_254_172_371_451_904,
+    362_033_640_947_955_990_528,
+    448_614_957_292_549_898_240,
+    555_902_427_684_966_825_984,
+    688_847_984_407_522_443_264,
+    853_587_827_630_813_609_984,
+    1_057_725_646_255_857_598_464,
+    1_310_683_571_780_334_911_488,
+    1_624_137_063_723_335_680_000,
+    2_012_553_799_065_962_807_296,
+    2_493_861_438_547_160_989_696,
+    3_090_275_091_060_396_392_448,
+    3_829_322_668_380_377_841_664,
+    4_745_115_456_223_062_261_760,
+    5_879_922_545_782_815_719_424,
+    7_286_121_794_794_915_102_720,
+    9_028_617_366_169_358_172_160,
+    11_187_835_427_473_716_150_272,
+    13_863_436_279_982_904_573_952,
+    17_178_914_253_348_563_582_976,
+    21_287_297_677_417_273_884_672,
+    26_378_212_017_598_590_091_264,
+    32_686_632_178_002_377_572_352,
+    40_503_727_941_348_413_669_376,
+    50_190_303_124_921_977_405_440,
+    62_193_448_746_725_178_081_280,
+    77_067_178_840_983_431_282_688,
+    95_498_001_384_283_463_221_248,
+    118_336_604_577_288_176_009_216,
+    146_637_120_985_718_228_779_008,
+    181_705_781_805_970_760_925_184,
+    225_161_206_928_870_056_394_752,
+    279_009_113_533_885_028_761_600,
+    345_734_891_444_050_809_847_808,
+    428_418_318_125_341_920_460_800,
+    530_875_708_085_854_936_236_032,
+    657_836_057_685_104_537_894_912,
+    815_159_315_446_949_618_909_184,
+    1_010_106_852_303_340_264_488_960,
+    1_251_676_615_522_360_284_413_952,
+    1_551_018_435_597_172_022_968_320,
+    1_921_948_654_891_462_874_365_952,
+    2_381_587_831_105_879_655_055_360,
+    2_951_150_949_239_024_882_024_448,
+    3_656_926_614_858_573_948_125_184,
+    4_531_490_424_069_748_254_834_688,
+    5_615_208_514_166_468_382_818_304,
+    6_958_100_692_453_826_974_711_808,
+    8_622_149_137_326_070_491_512_832,
+    10_684_159_231_400_203_916_410_880,
+    13_239_304_570_567_327_915_966_464,
+    16_405_519_771_467_677_366_747_136,
+    20_328_943_830_656_482_967_289_856,
+    25_190_665_277_715_519_786_450_944,
+    31_215_080_449_824_342_623_125_504,
+    38_680_250_670_115_298_562_015_232,
+    47_930_736_373_014_033_185_374_208,
+    59_393_500_545_081_206_682_681_344,
+    73_597_615_516_390_531_352_494_080,
+    91_198_682_683_926_012_250_554_368,
+    113_009_092_277_332_648_046_624_768,
+    140_035_519_828_814_537_164_849_152,
+    173_525_390_024_388_953_042_321_408,
+    215_024_452_509_801_542_882_164_736,
+    266_448_127_104_866_372_796_022_784,
+    330_169_911_417_190_367_616_827_392,
+    409_130_931_373_862_699_543_822_336,
+    506_975_691_056_654_089_231_269_888,
+    628_220_287_474_436_506_013_138_944,
+    778_460_854_349_643_015_195_197_440,
+    964_631_855_795_385_276_756_393_984,
+    1_195_326_151_618_295_177_836_232_704,
+    1_481_191_607_097_077_997_282_263_040,
+    1_835_422_553_053_465_322_211_770_368,
+    2_274_368_779_917_417_733_882_380_288,
+    2_818_290_174_356_576_533_370_372_096,
+    3_492_291_829_279_869_031_485_014_016,
+    4_327_482_787_906_798_210_290_548_736,
+    5_362_411_904_589_093_824_320_503_808,
+    6_644_847_095_599_389_361_569_792_000,
+    8_233_980_102_518_631_681_761_476_608,
+    10_203_158_530_701_610_603_113_349_120,
+    12_643_271_262_069_884_915_059_523_584,
+    15_666_943_498_455_093_217_991_852_032,
+    19_413_735_060_810_527_985_801_101_312,
+    24_056_581_875_622_974_696_821_096_448,
+    29_809_777_960_078_442_335_727_255_552,
+    36_938_866_320_391_013_629_886_988_288,
+    45_772_895_284_998_192_312_828_821_504,
+    56_719_605_972_716_050_931_970_473_984,
+    70_284_251_884_642_240_524_309_233_664,
+    87_092_919_251_943_946_606_587_412_480,
+    107_921_424_507_373_304_115_858_767_872,
+    133_731_122_664_609_837_022_506_385_408,
+    165_713_279_367_574_218_532_250_976_256,
+    205_344_054_634_358_193_676_970_098_688,
+    254_452_636_111_002_639_407_226_290_176,
+    315_305_666_575_675_922_194_335_006_720,
+    390_711_862_506_943_218_111_179_915_264,
+    484_151_652_463_264_139_873_108_361_216,
+    599_937_818_828_686_852_166_883_737_600,
+    743_414_557_462_926_922_605_337_247_744,
+    921_204_143_000_716_290_034_132_582_400,
+    1_141_512_584_819_141_767_883_592_302_592,
+    1_414_508_381_449_458_231_873_982_431_232,
+    1_752_791_855_122_449_440_184_668_782_592,
+    2_171_976_728_929_246_858_928_369_696_768,
+    2_691_410_789_720_168_708_048_433_446_912,
+    3_335_068_899_468_907_960_364_519_391_232,
+    4_132_659_572_699_862_859_971_051_913_216,
+    5_120_996_194_875_473_943_773_180_854_272,
+    6_345_696_171_339_023_487_001_843_204_096,
+    7_863_286_432_284_867_033_736_377_532_416,
+    9_743_812_474_890_060_380_031_449_825_280,
+    12_074_071_364_870_210_813_345_285_537_792,
+    14_961_617_919_029_550_050_656_552_747_008,
+    18_539_728_977_113_959_737_404_690_530_304,
+    22_973_554_892_593_708_182_828_786_647_040,
+    28_467_742_168_967_529_709_576_917_090_304,
+    35_275_879_069_985_620_727_142_650_216_448,
+    43_712_200_172_894_182_534_632_968_290_304,
+    54_166_090_096_984_481_657_348_916_510_720,
+    67_120_055_837_728_895_901_049_536_118_784,
+    83_171_997_232_834_275_962_374_076_760_064,
+    103_062_803_469_989_750_758_709_311_569_920,
+    127_710_549_373_466_870_903_302_443_237_376,
+    158_252_869_824_387_503_774_624_091_471_872,
+    196_099_468_137_263_108_065_748_783_726_592,
+    242_997_181_955_630_963_744_025_192_955_904,
+    301_110_609_831_163_051_860_019_912_900_608,
+    373_122_019_865_440_161_241_912_651_546_624,
+    462_355_151_771_399_016_556_108_672_139_264,
+    572_928_626_529_859_105_073_766_120_554_496,
+    709_946_044_376_910_370_226_557_857_824_768,
+    879_731_545_234_900_182_253_303_884_677_120,
+    1_090_121_704_052_353_436_739_676_793_733_120,
+    1_350_827_233_697_408_386_543_876_585_816_064,
+    1_673_881_190_068_443_605_018_067_389_644_800,
+    2_074_194_366_659_165_794_397_333_749_760_000,
+    2_570_243_513_223_719_937_217_567_964_266_496,
+    3_184_924_143_781_623_466_639_422_738_399_232,
+    3_946_607_295_944_636_360_249_530_610_876_416,
+    4_890_449_017_071_282_041_228_472_407_293_952,
+    6_060_013_017_547_761_202_822_676_192_690_176,
+    7_509_281_385_953_573_187_372_206_925_021_184,
+    9_305_146_172_152_490_877_257_243_636_531_200,
+    11_530_496_839_163_123_036_316_315_754_168_320,
+    14_288_046_087_426_038_444_840_729_138_167_808,
+    17_705_070_635_206_686_988_810_058_014_392_320,
+    21_939_285_769_348_255_354_744_679_119_716_352,
+    27_186_124_810_594_850_810_699_332_355_883_008,
+    33_687_759_482_573_019_047_073_247_664_275_456,
+    41_744_277_526_211_235_229_463_073_135_788_032,
+    51_727_533_470_630_460_182_974_054_952_599_552,
+    64_098_311_853_046_163_592_221_266_288_836_608,
+    79_427_595_068_748_257_784_872_089_304_432_640,
+    98_422_917_484_452_131_454_823_204_055_416_832,
+    121_961_022_208_549_387_541_411_278_807_367_680,
+    151_128_327_815_561_844_070_362_977_625_178_112,
+    187_271_072_796_295_988_047_574_050_619_260_928,
+    232_057_452_187_758_205_319_635_637_859_516_416,
+    287_554_614_344_788_418_037_131_667_885_260_800,
+    356_324_071_696_164_396_955_222_731_405_656_064,
+    441_539_929_238_956_520_521_663_052_511_182_848,
+    547_135_387_694_441_091_077_211_125_561_950_208,
+    677_984_283_286_727_619_908_262_612_600_619_008,
+    840_126_043_246_403_421_592_420_580_539_236_352,
+    1_041_044_440_026_291_174_381_109_671_263_993_856,
+    1_290_013_010_335_629_399_158_545_393_913_954_304,
+    1_598_523_082_062_822_980_394_509_025_429_946_368,
+    1_980_814_164_984_899_974_868_396_505_686_867_968,
+    2_454_531_185_837_844_259_424_851_507_206_422_528,
+    3_041_538_902_916_953_625_534_385_571_447_701_504,
+    3_768_931_090_113_441_650_881_862_854_808_436_736,
+    4_670_281_070_020_410_835_742_191_626_898_898_944,
+    5_787_191_315_385_520_991_126_465_253_879_054_336,
+    7_171_213_641_907_685_958_444_182_697_345_548_288,
+    8_886_228_620_291_787_833_869_426_467_518_021_632,
+    11_011_394_031_078_763_650_833_251_344_259_743_744,
+    13_644_798_450_357_182_065_499_097_024_216_170_496,
+    16_907_988_600_298_052_828_848_077_384_112_406_528,
+    20_951_579_427_714_110_213_372_150_538_043_392_000,
+    25_962_205_847_954_911_073_728_848_507_877_457_920,
+    32_171_137_017_001_678_623_444_398_121_836_085_248,
+    39_864_950_729_840_351_195_287_835_895_509_549_056,
+    49_398_760_629_838_383_869_001_023_180_029_034_496,
+    61_212_606_740_724_380_996_927_037_409_880_702_976,
+    75_851_765_838_256_308_379_938_873_011_815_317_504,
+    93_991_919_101_754_306_497_763_116_416_531_169_280,
+    116_470_338_677_006_802_880_637_904_783_566_438_400,
+    144_324_532_589_349_772_278_280_920_451_770_220_544,
+    178_840_131_691_369_343_950_514_982_081_500_545_024,
+    221_610_228_902_598_410_553_988_357_669_452_251_136,
+    274_608_909_587_557_166_392_846_221_827_647_733_760,
+    340_282_366_920_938_463_463_374_607_431_768_211_455,
+];
@@ -68,7 +68,7 @@ pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::Multiplier;
 
 mod precompiles;
-use precompiles::FrontierPrecompiles;
+use precompiles::{FrontierPrecompileAddresses, FrontierPrecompiles};
 
 impl_opaque_keys! {
 	pub struct SessionKeys {
@@ -358,6 +358,13 @@ impl pallet_hotfix_sufficients::Config for Runtime {
 	type WeightInfo = pallet_hotfix_sufficients::weights::SubstrateWeight<Self>;
 }
 
+impl pallet_precompile_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type Precompiles = FrontierPrecompileAddresses<Self>;
+	type WeightInfo = pallet_precompile_registry::weights::SubstrateWeight<Self>;
+}
+
 #[frame_support::pallet]
 pub mod pallet_manual_seal {
 	use super::*;
@@ -415,6 +422,8 @@ frame_support::construct_runtime!(
 		HotfixSufficients: pallet_hotfix_sufficients,
 
 		ManualSeal: pallet_manual_seal,
+
+		PrecompileRegistry: pallet_precompile_registry,
 	}
 );
 
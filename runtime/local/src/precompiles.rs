@@ -1,12 +1,52 @@
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::traits::Contains;
 use pallet_evm::{
 	IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
 };
+use pallet_precompile_registry::Pallet as PrecompileRegistry;
+use precompile_utils::prelude::{revert, RuntimeHelper};
 use sp_core::H160;
 use sp_std::marker::PhantomData;
 
+use crate::{AccountId, RuntimeCall};
+
+use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
+use pallet_evm_precompile_batch::BatchPrecompile;
+use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_call_permit::CallPermitPrecompile;
+use pallet_evm_precompile_curves::{
+	Bls12377G1Add, Bls12377G1Msm, Bls12377G2Add, Bls12377G2Msm, Bls12377Pairing, Bls12381G1Add,
+	Bls12381G1Msm, Bls12381G2Add, Bls12381G2Msm, Bls12381MapG1, Bls12381MapG2, Bls12381Pairing,
+	Bw6761G1Add, Bw6761G1Msm, Bw6761G2Add, Bw6761G2Msm, Bw6761Pairing,
+};
+use pallet_evm_precompile_dispatch::{Dispatch, DispatchValidateT};
+use pallet_evm_precompile_kzg::KzgPointEvaluation;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_p256::P256Verify;
+use pallet_evm_precompile_registry::PrecompileRegistryPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_substrate_crypto::{
+	Blake2b128, Blake2b256, Ed25519Verify, Sr25519Verify, Twox128, Twox64,
+};
+
+/// Metadata of the native IPT token as seen through its ERC-20 precompile.
+pub struct NativeErc20Metadata;
+
+impl Erc20Metadata for NativeErc20Metadata {
+	fn name() -> &'static str {
+		"IPT"
+	}
+
+	fn symbol() -> &'static str {
+		"IPT"
+	}
+
+	fn decimals() -> u8 {
+		18
+	}
+}
 
 pub struct FrontierPrecompiles<R>(PhantomData<R>);
 
@@ -17,42 +57,193 @@ where
 	pub fn new() -> Self {
 		Self(Default::default())
 	}
-	pub fn used_addresses() -> [H160; 7] {
+	/// Whether the precompile registry manages the status of the precompile at `address`.
+	///
+	/// The Ethereum precompiles are always enabled, so that calls to them cost what they do on
+	/// Ethereum instead of paying for reading a status.
+	pub fn is_managed(address: &H160) -> bool {
+		*address >= hash(1024) && Self::used_addresses().contains(address)
+	}
+	pub fn used_addresses() -> [H160; 41] {
 		[
 			hash(1),
 			hash(2),
 			hash(3),
 			hash(4),
 			hash(5),
+			hash(6),
+			hash(7),
+			hash(8),
+			hash(9),
+			hash(10),
+			hash(11),
+			hash(12),
+			hash(13),
+			hash(14),
+			hash(15),
+			hash(16),
+			hash(17),
+			hash(256),
 			hash(1024),
 			hash(1025),
+			hash(1026),
+			hash(1027),
+			hash(1028),
+			hash(1029),
+			hash(1030),
+			hash(1031),
+			hash(1032),
+			hash(1033),
+			hash(1034),
+			hash(1035),
+			hash(1036),
+			hash(1037),
+			hash(1038),
+			hash(1039),
+			hash(1040),
+			hash(1041),
+			hash(2049),
+			hash(2050),
+			hash(2051),
+			hash(2052),
+			hash(2053),
 		]
 	}
 }
 impl<R> PrecompileSet for FrontierPrecompiles<R>
 where
-	R: pallet_evm::Config,
+	R: pallet_evm::Config + pallet_precompile_registry::Config,
+	Erc20BalancesPrecompile<R, NativeErc20Metadata>: Precompile,
+	BatchPrecompile<R>: Precompile,
+	CallPermitPrecompile<R>: Precompile,
+	PrecompileRegistryPrecompile<R>: Precompile,
+	Dispatch<R, DispatchCallFilter>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-		match handle.code_address() {
+		let address = handle.code_address();
+		// The status read is paid for by the extra cost of `is_precompile`.
+		if Self::is_managed(&address) && !PrecompileRegistry::<R>::status(address).is_active() {
+			return Some(Err(revert("Precompile is disabled")));
+		}
+
+		match address {
 			// Ethereum precompiles :
 			a if a == hash(1) => Some(ECRecover::execute(handle)),
 			a if a == hash(2) => Some(Sha256::execute(handle)),
 			a if a == hash(3) => Some(Ripemd160::execute(handle)),
 			a if a == hash(4) => Some(Identity::execute(handle)),
 			a if a == hash(5) => Some(Modexp::execute(handle)),
+			a if a == hash(6) => Some(Bn128Add::execute(handle)),
+			a if a == hash(7) => Some(Bn128Mul::execute(handle)),
+			a if a == hash(8) => Some(Bn128Pairing::execute(handle)),
+			a if a == hash(9) => Some(Blake2F::execute(handle)),
+			a if a == hash(10) => Some(KzgPointEvaluation::execute(handle)),
+			a if a == hash(11) => Some(Bls12381G1Add::execute(handle)),
+			a if a == hash(12) => Some(Bls12381G1Msm::execute(handle)),
+			a if a == hash(13) => Some(Bls12381G2Add::execute(handle)),
+			a if a == hash(14) => Some(Bls12381G2Msm::execute(handle)),
+			a if a == hash(15) => Some(Bls12381Pairing::execute(handle)),
+			a if a == hash(16) => Some(Bls12381MapG1::execute(handle)),
+			a if a == hash(17) => Some(Bls12381MapG2::execute(handle)),
+			a if a == hash(256) => Some(P256Verify::execute(handle)),
 			// Non-Frontier specific nor Ethereum precompiles :
 			a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
 			a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
+			a if a == hash(1026) => Some(Bls12377G1Add::execute(handle)),
+			a if a == hash(1027) => Some(Bls12377G1Msm::execute(handle)),
+			a if a == hash(1028) => Some(Bls12377G2Add::execute(handle)),
+			a if a == hash(1029) => Some(Bls12377G2Msm::execute(handle)),
+			a if a == hash(1030) => Some(Bls12377Pairing::execute(handle)),
+			a if a == hash(1031) => Some(Bw6761G1Add::execute(handle)),
+			a if a == hash(1032) => Some(Bw6761G1Msm::execute(handle)),
+			a if a == hash(1033) => Some(Bw6761G2Add::execute(handle)),
+			a if a == hash(1034) => Some(Bw6761G2Msm::execute(handle)),
+			a if a == hash(1035) => Some(Bw6761Pairing::execute(handle)),
+			a if a == hash(1036) => Some(Sr25519Verify::execute(handle)),
+			a if a == hash(1037) => Some(Ed25519Verify::execute(handle)),
+			a if a == hash(1038) => Some(Blake2b128::execute(handle)),
+			a if a == hash(1039) => Some(Blake2b256::execute(handle)),
+			a if a == hash(1040) => Some(Twox64::execute(handle)),
+			a if a == hash(1041) => Some(Twox128::execute(handle)),
+			// Impetus specific precompiles :
+			a if a == hash(2049) => {
+				Some(Erc20BalancesPrecompile::<R, NativeErc20Metadata>::execute(handle))
+			}
+			a if a == hash(2050) => Some(BatchPrecompile::<R>::execute(handle)),
+			a if a == hash(2051) => Some(CallPermitPrecompile::<R>::execute(handle)),
+			a if a == hash(2052) => Some(PrecompileRegistryPrecompile::<R>::execute(handle)),
+			a if a == hash(2053) => Some(Dispatch::<R, DispatchCallFilter>::execute(handle)),
 			_ => None,
 		}
 	}
 
 	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
-		IsPrecompileResult::Answer {
-			is_precompile: Self::used_addresses().contains(&address),
-			extra_cost: 0,
+		// Calls to a managed precompile read its status in the registry.
+		if Self::is_managed(&address) {
+			IsPrecompileResult::Answer {
+				is_precompile: true,
+				extra_cost: RuntimeHelper::<R>::db_read_gas_cost(),
+			}
+		} else if Self::used_addresses().contains(&address) {
+			IsPrecompileResult::Answer {
+				is_precompile: true,
+				extra_cost: 0,
+			}
+		} else {
+			IsPrecompileResult::Answer {
+				is_precompile: false,
+				extra_cost: 0,
+			}
+		}
+	}
+}
+
+/// Addresses of the [`FrontierPrecompiles`] whose status the precompile registry manages, all
+/// but the Ethereum precompiles.
+pub struct FrontierPrecompileAddresses<R>(PhantomData<R>);
+
+impl<R> Contains<H160> for FrontierPrecompileAddresses<R>
+where
+	R: pallet_evm::Config,
+{
+	fn contains(address: &H160) -> bool {
+		FrontierPrecompiles::<R>::is_managed(address)
+	}
+}
+
+/// Calls that the dispatch precompile makes on behalf of the calling EVM account.
+///
+/// Only the pallets that accounts use directly are allowed, so `Sudo`, `System::set_code` and
+/// the EVM pallets, through which a dispatched call would re-enter the EVM, are never
+/// dispatched. A utility batch is allowed when every call in it is.
+pub struct DispatchCallFilter;
+
+impl DispatchCallFilter {
+	fn is_allowed(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls }
+				| pallet_utility::Call::batch_all { calls }
+				| pallet_utility::Call::force_batch { calls },
+			) => calls.iter().all(Self::is_allowed),
+			RuntimeCall::Balances(_) => true,
+			_ => false,
+		}
+	}
+}
+
+impl DispatchValidateT<AccountId, RuntimeCall> for DispatchCallFilter {
+	fn validate_before_dispatch(
+		origin: &AccountId,
+		call: &RuntimeCall,
+	) -> Option<PrecompileFailure> {
+		if !Self::is_allowed(call) {
+			return Some(PrecompileFailure::Error {
+				exit_status: ExitError::Other("call not allowed".into()),
+			});
 		}
+		// Frontier's own check: only normal calls that pay a fee.
+		<() as DispatchValidateT<AccountId, RuntimeCall>>::validate_before_dispatch(origin, call)
 	}
 }
 